@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use clap::{Parser, Subcommand, ValueEnum};
+use sqruff_lib::core::linter::cache::LintCache;
 
 #[derive(Debug, Parser)]
 #[command(name = "sqruff")]
@@ -16,6 +17,10 @@ pub enum Commands {
     Lint(LintArgs),
     #[command(name = "fix", about = "fix files")]
     Fix(FixArgs),
+    #[command(name = "lsp", about = "start a language server, speaking over stdio")]
+    Lsp,
+    #[command(name = "rules", about = "list the rules, or explain the given ones")]
+    Rules(RulesArgs),
 }
 
 #[derive(Debug, Parser)]
@@ -23,6 +28,25 @@ pub struct LintArgs {
     pub paths: Vec<PathBuf>,
     #[arg(default_value = "human", short, long)]
     pub format: Format,
+    /// Print per-file and aggregated timings of each step, and the slowest
+    /// rules.
+    #[arg(long, alias = "bench")]
+    pub timing: bool,
+    /// Print how many grammar elements parsing each file tried, and the
+    /// deepest they nested.
+    #[arg(long)]
+    pub parse_statistics: bool,
+    /// Lint every file, rather than reusing the results of files which
+    /// haven't changed since they were last linted.
+    #[arg(long)]
+    pub no_cache: bool,
+    /// Where to keep the results of linting files, to reuse them.
+    #[arg(long, default_value = LintCache::DEFAULT_DIR)]
+    pub cache_dir: PathBuf,
+    /// Write the report to this file rather than stdout, still printing the
+    /// human-readable output to the terminal.
+    #[arg(long)]
+    pub write_output: Option<PathBuf>,
 }
 
 #[derive(Debug, Parser)]
@@ -32,7 +56,22 @@ pub struct FixArgs {
     #[arg(long)]
     pub force: bool,
     #[arg(default_value = "human", short, long)]
-    pub format: Format,
+    pub format: FixFormat,
+    /// Print per-file and aggregated timings of each step, and the slowest
+    /// rules.
+    #[arg(long, alias = "bench")]
+    pub timing: bool,
+    /// Print how many grammar elements parsing each file tried, and the
+    /// deepest they nested.
+    #[arg(long)]
+    pub parse_statistics: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct RulesArgs {
+    /// The codes, names, groups or aliases of the rules to explain, e.g.
+    /// `CV02` or `LT*`.
+    pub rules: Vec<String>,
 }
 
 #[derive(Debug, Default, Clone, Copy, ValueEnum)]
@@ -40,4 +79,17 @@ pub enum Format {
     #[default]
     Human,
     GithubAnnotationNative,
+    Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+}
+
+/// The formats `fix` can report in, as the others are for checking files
+/// rather than changing them.
+#[derive(Debug, Default, Clone, Copy, ValueEnum)]
+pub enum FixFormat {
+    #[default]
+    Human,
+    Json,
 }
@@ -0,0 +1,469 @@
+use std::collections::HashMap;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, Formatting, RegisterCapability, Request as _};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DocumentFormattingParams, FileSystemWatcher, GlobPattern,
+    InitializeParams, NumberOrString, OneOf, Position, PublishDiagnosticsParams, Range,
+    Registration, RegistrationParams, ServerCapabilities, TextDocumentSyncKind, TextEdit, Url,
+    WorkspaceEdit,
+};
+use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::errors::SQLLintError;
+use sqruff_lib::core::linter::linted_file::LintedFile;
+use sqruff_lib::core::linter::linter::Linter;
+use sqruff_lib::core::rules::base::ErasedRule;
+
+/// Serve the language server protocol over stdin and stdout until the client
+/// asks to shut down.
+pub(crate) fn run() {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncKind::FULL.into()),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..ServerCapabilities::default()
+    };
+    let params = connection.initialize(serde_json::to_value(capabilities).unwrap()).unwrap();
+    let params: InitializeParams = serde_json::from_value(params).unwrap();
+
+    // Config is looked up from the working directory, which should be the
+    // root of the workspace.
+    #[allow(deprecated)]
+    if let Some(root) = params.root_uri.and_then(|root| root.to_file_path().ok()) {
+        _ = std::env::set_current_dir(root);
+    }
+
+    let mut server = LanguageServer::new(connection);
+
+    let can_watch = params
+        .capabilities
+        .workspace
+        .and_then(|workspace| workspace.did_change_watched_files)
+        .and_then(|watched_files| watched_files.dynamic_registration)
+        .unwrap_or_default();
+    if can_watch {
+        server.watch_config();
+    }
+
+    server.main_loop();
+    io_threads.join().unwrap();
+}
+
+struct LanguageServer {
+    connection: Connection,
+    linter: Linter,
+    /// The rules of `linter`, which are built once per config.
+    rules: Vec<ErasedRule>,
+    /// The text of every open document.
+    documents: HashMap<Url, String>,
+}
+
+impl LanguageServer {
+    fn new(connection: Connection) -> Self {
+        let linter = linter();
+        let rules = rules(&linter);
+        Self { connection, linter, rules, documents: HashMap::new() }
+    }
+
+    fn reload_config(&mut self) {
+        self.linter = linter();
+        self.rules = rules(&self.linter);
+    }
+
+    fn main_loop(mut self) {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request).unwrap() {
+                        return;
+                    }
+                    self.on_request(request);
+                }
+                Message::Notification(notification) => self.on_notification(notification),
+                Message::Response(_) => {}
+            }
+        }
+    }
+
+    fn on_request(&mut self, request: Request) {
+        let result = match request.method.as_str() {
+            CodeActionRequest::METHOD => match serde_json::from_value(request.params) {
+                Ok(params) => serde_json::to_value(self.code_actions(params)),
+                Err(error) => return self.invalid_params(request.id, error),
+            },
+            Formatting::METHOD => match serde_json::from_value(request.params) {
+                Ok(params) => serde_json::to_value(self.format(params)),
+                Err(error) => return self.invalid_params(request.id, error),
+            },
+            method => {
+                let message = format!("unhandled request: {method}");
+                let response =
+                    Response::new_err(request.id, ErrorCode::MethodNotFound as i32, message);
+                return self.send(response);
+            }
+        };
+
+        self.send(Response::new_ok(request.id, result.unwrap()));
+    }
+
+    fn invalid_params(&self, id: RequestId, error: serde_json::Error) {
+        let message = format!("invalid params: {error}");
+        self.send(Response::new_err(id, ErrorCode::InvalidParams as i32, message));
+    }
+
+    /// Handle a notification, dropping it if its params are invalid as there's
+    /// no way to reply to one.
+    fn on_notification(&mut self, notification: Notification) {
+        let method = notification.method;
+
+        macro_rules! params {
+            ($ty:ty) => {
+                match serde_json::from_value::<$ty>(notification.params) {
+                    Ok(params) => params,
+                    Err(error) => {
+                        eprintln!("Dropping {method} notification with invalid params: {error}");
+                        return;
+                    }
+                }
+            };
+        }
+
+        match method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = params!(DidOpenTextDocumentParams);
+                let uri = params.text_document.uri;
+
+                self.documents.insert(uri.clone(), params.text_document.text);
+                self.publish_diagnostics(uri);
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = params!(DidChangeTextDocumentParams);
+                let uri = params.text_document.uri;
+
+                // Documents are synced in full, so the last change is the
+                // whole text.
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents.insert(uri.clone(), change.text);
+                    self.publish_diagnostics(uri);
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = params!(DidCloseTextDocumentParams);
+                let uri = params.text_document.uri;
+
+                self.documents.remove(&uri);
+                self.send_diagnostics(uri, Vec::new());
+            }
+            DidChangeWatchedFiles::METHOD => {
+                self.reload_config();
+
+                let uris: Vec<_> = self.documents.keys().cloned().collect();
+                for uri in uris {
+                    self.publish_diagnostics(uri);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Ask the client to tell us about changes to config files, to reload
+    /// them.
+    fn watch_config(&mut self) {
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: vec![FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/.sqlfluff".into()),
+                kind: None,
+            }],
+        };
+        let params = RegistrationParams {
+            registrations: vec![Registration {
+                id: "sqruff/config".into(),
+                method: DidChangeWatchedFiles::METHOD.into(),
+                register_options: Some(serde_json::to_value(options).unwrap()),
+            }],
+        };
+
+        let id = RequestId::from("sqruff/watch-config".to_string());
+        self.send(Request::new(id, RegisterCapability::METHOD.into(), params));
+    }
+
+    fn lint(&mut self, uri: &Url, fix: bool) -> Option<LintedFile> {
+        let text = self.documents.get(uri)?.clone();
+        let rules = self.rules.clone();
+        let mut result =
+            self.linter.lint_string_wrapped(text, Some(uri.path().into()), Some(fix), rules);
+
+        result.paths.pop()?.files.pop()
+    }
+
+    fn publish_diagnostics(&mut self, uri: Url) {
+        let Some(file) = self.lint(&uri, false) else { return };
+        let text = &self.documents[&uri];
+
+        let diagnostics = file.violations.iter().map(|violation| diagnostic(text, violation));
+        self.send_diagnostics(uri, diagnostics.collect());
+    }
+
+    fn send_diagnostics(&self, uri: Url, diagnostics: Vec<Diagnostic>) {
+        let params = PublishDiagnosticsParams { uri, diagnostics, version: None };
+        self.send(Notification::new(PublishDiagnostics::METHOD.into(), params));
+    }
+
+    /// A quick fix for every fixable violation in the requested range.
+    fn code_actions(&mut self, params: CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let uri = params.text_document.uri;
+        let Some(file) = self.lint(&uri, false) else { return Vec::new() };
+        let text = &self.documents[&uri];
+
+        let lines = params.range.start.line..=params.range.end.line;
+        let in_range = |violation: &&SQLLintError| {
+            lines.contains(&(violation.line_no.saturating_sub(1) as u32))
+        };
+
+        file.violations
+            .iter()
+            .filter(in_range)
+            .filter_map(|violation| {
+                let fixed = self.linter.fix_violation(&file, violation)?;
+                let changes = HashMap::from([(uri.clone(), vec![text_edit(text, &fixed)])]);
+
+                let action = CodeAction {
+                    title: format!("Fix {}: {}", violation.rule_code(), violation.description),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic(text, violation)]),
+                    edit: Some(WorkspaceEdit { changes: Some(changes), ..<_>::default() }),
+                    ..<_>::default()
+                };
+                Some(action.into())
+            })
+            .collect()
+    }
+
+    fn format(&mut self, params: DocumentFormattingParams) -> Vec<TextEdit> {
+        let uri = params.text_document.uri;
+        let Some(file) = self.lint(&uri, true) else { return Vec::new() };
+        let text = &self.documents[&uri];
+
+        let fixed = file.fix_string();
+        if &fixed == text { Vec::new() } else { vec![text_edit(text, &fixed)] }
+    }
+
+    fn send(&self, message: impl Into<Message>) {
+        self.connection.sender.send(message.into()).unwrap();
+    }
+}
+
+fn linter() -> Linter {
+    let config = FluffConfig::from_root(None, false, None).unwrap_or_else(|error| {
+        eprintln!("{error}. Falling back to the default config.");
+        FluffConfig::new(<_>::default(), None, None)
+    });
+    Linter::new(config, None, None)
+}
+
+/// The rules selected by the config of `linter`, logging any problems with
+/// it.
+fn rules(linter: &Linter) -> Vec<ErasedRule> {
+    let rule_pack = linter.get_rulepack();
+    for warning in &rule_pack.warnings {
+        eprintln!("{warning}");
+    }
+    rule_pack.rules()
+}
+
+fn diagnostic(text: &str, violation: &SQLLintError) -> Diagnostic {
+    let start = position_at_line(text, violation.line_no, violation.line_pos);
+    let end = position_at_line(text, violation.end_line_no, violation.end_line_pos);
+    let severity =
+        if violation.warning { DiagnosticSeverity::WARNING } else { DiagnosticSeverity::ERROR };
+
+    Diagnostic {
+        range: Range::new(start, end),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(violation.rule_code().into())),
+        source: Some("sqruff".into()),
+        message: violation.description.clone(),
+        ..<_>::default()
+    }
+}
+
+fn text_edit(old: &str, new: &str) -> TextEdit {
+    let edit = sqruff_lib::core::linter::common::TextEdit::between(old, new);
+
+    TextEdit {
+        range: Range::new(position(old, edit.range.start), position(old, edit.range.end)),
+        new_text: edit.replacement,
+    }
+}
+
+/// The position of a byte offset into `text`, counting characters in UTF-16
+/// code units as the protocol does.
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let character = before[line_start..].encode_utf16().count();
+
+    Position::new(line as u32, character as u32)
+}
+
+/// The position of a 1-based line and byte position, as violations are
+/// reported.
+fn position_at_line(text: &str, line_no: usize, line_pos: usize) -> Position {
+    let line_no = line_no.saturating_sub(1);
+    let line = text.lines().nth(line_no).unwrap_or_default();
+    let end = line_pos.saturating_sub(1).min(line.len());
+    let character = line.get(..end).unwrap_or(line).encode_utf16().count();
+
+    Position::new(line_no as u32, character as u32)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use std::thread;
+
+    use lsp_server::{Connection, Message, Notification, Request, RequestId};
+    use lsp_types::notification::{
+        DidOpenTextDocument, Exit, Notification as _, PublishDiagnostics,
+    };
+    use lsp_types::request::{Formatting, Request as _, Shutdown};
+    use lsp_types::{
+        DidOpenTextDocumentParams, DocumentFormattingParams, FormattingOptions, NumberOrString,
+        Position, PublishDiagnosticsParams, Range, TextDocumentIdentifier, TextDocumentItem,
+        TextEdit, Url,
+    };
+
+    use super::{position, position_at_line, text_edit, LanguageServer};
+
+    #[test]
+    fn test__lsp__position() {
+        let text = "SELECT '😀' ,b\r\nFROM t\r\n";
+
+        // The emoji is four bytes, but two UTF-16 code units.
+        assert_eq!(position(text, text.find(" ,").unwrap()), Position::new(0, 11));
+        assert_eq!(position(text, text.find("FROM").unwrap()), Position::new(1, 0));
+        assert_eq!(position(text, text.find(" t").unwrap()), Position::new(1, 4));
+        assert_eq!(position(text, text.len()), Position::new(2, 0));
+    }
+
+    #[test]
+    fn test__lsp__position_at_line() {
+        let text = "SELECT 'é' ,b\r\nFROM '😀' ,c\r\n";
+
+        assert_eq!(position_at_line(text, 1, 12), Position::new(0, 10));
+        assert_eq!(position_at_line(text, 2, 12), Position::new(1, 9));
+        // Positions past the end of the line, which the line ending doesn't
+        // count towards, are clamped to it.
+        assert_eq!(position_at_line(text, 1, 100), Position::new(0, 13));
+    }
+
+    #[test]
+    fn test__lsp__text_edit() {
+        let edit = text_edit("SELECT '😀' ,b\r\nFROM t\r\n", "SELECT '😀', b\r\nFROM t\r\n");
+
+        assert_eq!(
+            edit,
+            TextEdit {
+                range: Range::new(Position::new(0, 11), Position::new(0, 13)),
+                new_text: ", ".into()
+            }
+        );
+    }
+
+    /// Run a language server on an in-memory connection, and shut it down once
+    /// `client` is done talking to it.
+    fn with_server(client: impl FnOnce(&Connection)) {
+        let (server, connection) = Connection::memory();
+        let server = thread::spawn(move || LanguageServer::new(server).main_loop());
+
+        client(&connection);
+
+        let shutdown = Request::new(RequestId::from(0), Shutdown::METHOD.into(), ());
+        connection.sender.send(shutdown.into()).unwrap();
+        while let Ok(message) = connection.receiver.recv() {
+            if matches!(message, Message::Response(response) if response.id == 0.into()) {
+                break;
+            }
+        }
+        let exit = Notification::new(Exit::METHOD.into(), ());
+        connection.sender.send(exit.into()).unwrap();
+        server.join().unwrap();
+    }
+
+    fn open(connection: &Connection, uri: &Url, text: &str) {
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "sql".into(), 0, text.into()),
+        };
+        let notification = Notification::new(DidOpenTextDocument::METHOD.into(), params);
+        connection.sender.send(notification.into()).unwrap();
+    }
+
+    #[test]
+    fn test__lsp__did_open_publishes_diagnostics() {
+        let uri = Url::parse("file:///query.sql").unwrap();
+
+        with_server(|connection| {
+            open(connection, &uri, "SELECT '😀' ,b\r\nFROM t\r\n");
+
+            let Message::Notification(notification) = connection.receiver.recv().unwrap() else {
+                panic!("expected diagnostics to be published");
+            };
+            assert_eq!(notification.method, PublishDiagnostics::METHOD);
+
+            let params: PublishDiagnosticsParams =
+                serde_json::from_value(notification.params).unwrap();
+            assert_eq!(params.uri, uri);
+
+            let diagnostic = params
+                .diagnostics
+                .iter()
+                .find(|diagnostic| diagnostic.message == "Unexpected whitespace before \",\".")
+                .unwrap();
+            assert_eq!(diagnostic.code, Some(NumberOrString::String("LT01".into())));
+            assert_eq!(diagnostic.range, Range::new(Position::new(0, 11), Position::new(0, 12)));
+        });
+    }
+
+    #[test]
+    fn test__lsp__formatting() {
+        let uri = Url::parse("file:///query.sql").unwrap();
+
+        with_server(|connection| {
+            open(connection, &uri, "SELECT '😀'  || a\r\nFROM t\r\n");
+            // The diagnostics of opening the document.
+            connection.receiver.recv().unwrap();
+
+            let params = DocumentFormattingParams {
+                text_document: TextDocumentIdentifier::new(uri.clone()),
+                options: FormattingOptions { tab_size: 4, insert_spaces: true, ..<_>::default() },
+                work_done_progress_params: <_>::default(),
+            };
+            let request = Request::new(RequestId::from(1), Formatting::METHOD.into(), params);
+            connection.sender.send(request.into()).unwrap();
+
+            let Message::Response(response) = connection.receiver.recv().unwrap() else {
+                panic!("expected a response to formatting");
+            };
+            assert_eq!(response.id, 1.into());
+
+            let edits: Vec<TextEdit> = serde_json::from_value(response.result.unwrap()).unwrap();
+            assert_eq!(
+                edits,
+                [TextEdit {
+                    range: Range::new(Position::new(0, 12), Position::new(0, 13)),
+                    new_text: String::new(),
+                }]
+            );
+        });
+    }
+}
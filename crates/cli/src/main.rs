@@ -1,40 +1,98 @@
 use clap::Parser as _;
-use commands::{FixArgs, Format, LintArgs};
+use commands::{FixArgs, FixFormat, Format, LintArgs, RulesArgs};
+use sqruff_lib::cli::checkstyle::checkstyle;
 use sqruff_lib::cli::formatters::OutputStreamFormatter;
+use sqruff_lib::cli::junit::junit;
+use sqruff_lib::cli::sarif::sarif;
 use sqruff_lib::core::config::FluffConfig;
 use sqruff_lib::core::linter::linter::Linter;
+use sqruff_lib::core::linter::linting_result::LintingResult;
+use sqruff_lib::core::rules::base::RuleManifest;
 
 use crate::commands::{Cli, Commands};
 
 mod commands;
+mod lsp;
 
 #[cfg(all(feature = "jemalloc", not(target_env = "msvc")))]
 #[global_allocator]
 static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
 fn main() {
-    let config = FluffConfig::from_root(None, false, None).unwrap();
+    let config = FluffConfig::from_root(None, false, None).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        std::process::exit(1);
+    });
 
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Lint(LintArgs { paths, format }) => {
-            let mut linter = linter(config, format);
+        Commands::Lint(LintArgs {
+            paths,
+            format,
+            timing,
+            parse_statistics,
+            no_cache,
+            cache_dir,
+            write_output,
+        }) => {
+            let human_output = matches!(format, Format::Human) || write_output.is_some();
+            let mut linter = linter(config, human_output, parse_statistics);
+            // Cached results have neither the fixes which SARIF output
+            // includes nor parse statistics.
+            if !no_cache && !parse_statistics && !matches!(format, Format::Sarif) {
+                linter = linter.with_cache(cache_dir);
+            }
             let result = linter.lint_paths(paths, false);
 
+            if timing {
+                print_timing(&result);
+            }
+            if parse_statistics {
+                print_parse_statistics(&result);
+            }
+
+            let report = match format {
+                Format::Json => Some(serde_json::to_string(&result.as_records()).unwrap()),
+                Format::Sarif => Some(serde_json::to_string(&sarif(&linter, &result)).unwrap()),
+                Format::Junit => Some(junit(&result)),
+                Format::Checkstyle => Some(checkstyle(&result)),
+                Format::Human | Format::GithubAnnotationNative => None,
+            };
+
+            if let Some(report) = report {
+                match write_output {
+                    Some(path) => {
+                        if let Err(error) = std::fs::write(&path, report) {
+                            eprintln!("Unable to write the report to {path:?}: {error}");
+                            std::process::exit(1);
+                        }
+                    }
+                    None => println!("{}", report.trim_end()),
+                }
+            }
+
             if let Format::GithubAnnotationNative = format {
                 for path in result.paths {
                     for file in path.files {
-                        for violation in file.violations {
-                            let mut line = "::error ".to_string();
-                            line.push_str("title=SQLFluff,");
+                        for violation in &file.violations {
+                            let col = file.column(violation.line_no, violation.line_pos);
+                            let end_col =
+                                file.column(violation.end_line_no, violation.end_line_pos);
+
+                            let mut line =
+                                if violation.warning { "::warning " } else { "::error " }
+                                    .to_string();
+                            line.push_str("title=sqruff,");
                             line.push_str(&format!("file={},", file.path));
                             line.push_str(&format!("line={},", violation.line_no));
-                            line.push_str(&format!("col={}", violation.line_pos));
+                            line.push_str(&format!("col={col},"));
+                            line.push_str(&format!("endLine={},", violation.end_line_no));
+                            line.push_str(&format!("endColumn={end_col}"));
                             line.push_str("::");
                             line.push_str(&format!(
                                 "{}: {}",
-                                violation.rule.as_ref().unwrap().code(),
+                                violation.rule_code(),
                                 violation.description
                             ));
                             eprintln!("{line}");
@@ -45,19 +103,31 @@ fn main() {
 
             std::process::exit(if linter.formatter.unwrap().has_fail.get() { 1 } else { 0 })
         }
-        Commands::Fix(FixArgs { paths, force, format }) => {
-            let mut linter = linter(config, format);
+        Commands::Fix(FixArgs { paths, force, format, timing, parse_statistics }) => {
+            let mut linter = linter(config, matches!(format, FixFormat::Human), parse_statistics);
             let result = linter.lint_paths(paths, true);
 
+            if timing {
+                print_timing(&result);
+            }
+            if parse_statistics {
+                print_parse_statistics(&result);
+            }
+
+            if let FixFormat::Json = format {
+                print_json(&result);
+            }
+
             if !force {
                 match check_user_input() {
                     Some(true) => {
-                        println!("Attempting fixes...");
+                        eprintln!("Attempting fixes...");
                     }
                     Some(false) => return,
                     None => {
-                        println!("Invalid input, please enter 'Y' or 'N'");
-                        println!("Aborting...");
+                        eprintln!("Invalid input, please enter 'Y' or 'N'");
+                        eprintln!("Aborting...");
+                        return;
                     }
                 }
             }
@@ -65,37 +135,155 @@ fn main() {
             for linted_dir in result.paths {
                 for file in linted_dir.files {
                     let write_buff = file.fix_string();
-                    std::fs::write(file.path, write_buff).unwrap();
+                    std::fs::write(file.path, file.encoding.encode(&write_buff)).unwrap();
                 }
             }
 
             linter.formatter.as_mut().unwrap().completion_message();
         }
+        Commands::Lsp => lsp::run(),
+        Commands::Rules(RulesArgs { rules }) => {
+            let linter = Linter::new(config, None, None);
+            let ruleset = linter.get_ruleset();
+
+            if rules.is_empty() {
+                for manifest in ruleset.manifests() {
+                    println!(
+                        "{}: {} - {}",
+                        manifest.code(),
+                        manifest.name(),
+                        manifest.description()
+                    );
+                }
+                return;
+            }
+
+            let manifests = ruleset.select(rules);
+            if manifests.is_empty() {
+                eprintln!("No rules match the given references.");
+                std::process::exit(1);
+            }
+
+            for manifest in manifests {
+                print_rule(manifest);
+            }
+        }
     }
 }
 
-fn linter(config: FluffConfig, format: Format) -> Linter {
-    let output_stream: Box<dyn std::io::Write> = match format {
-        Format::Human => Box::new(std::io::stderr()),
-        Format::GithubAnnotationNative => Box::new(std::io::sink()),
-    };
+fn print_rule(manifest: &RuleManifest) {
+    let rule = &manifest.rule_class;
+
+    println!("==== {}: {} ====", manifest.code(), manifest.name());
+    println!("{}", manifest.description());
+    println!();
+
+    if !manifest.aliases.is_empty() {
+        println!("Aliases: {}", manifest.aliases.join(", "));
+    }
+    println!("Groups: {}", manifest.groups.join(", "));
+
+    let docs = rule.long_description().trim();
+    if !docs.is_empty() {
+        println!();
+        println!("{docs}");
+    }
+
+    let options = rule.config_options();
+    if !options.is_empty() {
+        println!();
+        println!("Configuration ([sqlfluff:rules:{}]):", rule.config_ref());
+        for option in options {
+            print!("  {} = {}", option.name, option.default);
+            if !option.description.is_empty() {
+                print!("  # {}", option.description);
+            }
+            println!();
+        }
+    }
 
-    let formatter = OutputStreamFormatter::new(
+    println!();
+}
+
+fn linter(config: FluffConfig, human_output: bool, parse_statistics: bool) -> Linter {
+    let output_stream: Box<dyn std::io::Write> =
+        if human_output { Box::new(std::io::stderr()) } else { Box::new(std::io::sink()) };
+
+    let mut formatter = OutputStreamFormatter::new(
         output_stream,
         config.get("nocolor", "core").as_bool().unwrap_or_default(),
     );
+    // Warnings about the config are still shown alongside machine-readable
+    // output.
+    if !human_output {
+        formatter = formatter.warnings_to(Box::new(std::io::stderr()));
+    }
 
-    Linter::new(config, formatter.into(), None)
+    let linter = Linter::new(config, formatter.into(), None);
+    if parse_statistics { linter.with_parse_statistics() } else { linter }
+}
+
+fn print_json(result: &LintingResult) {
+    println!("{}", serde_json::to_string(&result.as_records()).unwrap());
+}
+
+fn print_timing(result: &LintingResult) {
+    const SLOWEST_RULES: usize = 10;
+
+    let steps = ["templating", "lexing", "parsing", "linting", "fixing"];
+
+    eprintln!("==== File timings ====");
+    for file in result.paths.iter().flat_map(|path| &path.files) {
+        let timings: Vec<_> = steps
+            .iter()
+            .filter_map(|&step| file.time_dict.get(step).map(|time| format!("{step}: {time:.4}")))
+            .collect();
+        let timings = timings.join(", ");
+        eprintln!("{}: {timings}", file.path);
+    }
+
+    eprintln!("==== Overall timings ====");
+    eprintln!("Clock time: {:.4}", result.total_time());
+
+    for (step, summary) in result.timing_summary().summary() {
+        eprintln!(
+            "{step}: cnt={} sum={:.4} min={:.4} max={:.4} avg={:.4}",
+            summary.count, summary.sum, summary.min, summary.max, summary.avg
+        );
+    }
+
+    eprintln!("==== Slowest rules ====");
+    for (code, summary) in result.rule_timing_summary().summary().into_iter().take(SLOWEST_RULES) {
+        eprintln!(
+            "{code}: {}: cnt={} sum={:.4} avg={:.4} segments={}",
+            summary.name,
+            summary.time.count,
+            summary.time.sum,
+            summary.time.avg,
+            summary.crawled_segments
+        );
+    }
+}
+
+fn print_parse_statistics(result: &LintingResult) {
+    for file in result.paths.iter().flat_map(|path| &path.files) {
+        if let Some(statistics) = &file.parse_statistics {
+            eprintln!("{}:", file.path);
+            eprint!("{statistics}");
+        }
+    }
 }
 
+/// Ask whether to apply the fixes, on stderr so that stdout is only the
+/// report.
 fn check_user_input() -> Option<bool> {
     use std::io::Write;
 
-    let mut term = console::Term::stdout();
+    let mut term = console::Term::stderr();
     _ = term.write(b"Are you sure you wish to attempt to fix these? [Y/n] ").unwrap();
     term.flush().unwrap();
 
-    let ret = match term.read_char().unwrap().to_ascii_lowercase() {
+    let ret = match term.read_char().ok()?.to_ascii_lowercase() {
         'y' | '\r' | '\n' => Some(true),
         'n' => Some(false),
         _ => None,
@@ -10,7 +10,7 @@ fn main() -> Result<()> {
     config.host = Some("".into());
     config.program.program = format!("../../target/{profile}/sqruff").into();
     config.program.out_dir_flag = None;
-    config.program.args = vec!["lint".into()];
+    config.program.args = vec!["lint".into(), "--no-cache".into()];
 
     std::mem::swap(&mut config.comment_defaults.base().edition, &mut <_>::default());
     config.comment_defaults.base().mode =
@@ -0,0 +1,120 @@
+//! Generates the rule documentation which is available at runtime, from the
+//! doc comments of the rules and the defaults of their config.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+const RULES_DIR: &str = "src/rules";
+const DEFAULT_CONFIG: &str = "src/core/default_config.cfg";
+
+fn main() {
+    println!("cargo:rerun-if-changed={RULES_DIR}");
+    println!("cargo:rerun-if-changed={DEFAULT_CONFIG}");
+
+    let mut out = String::new();
+
+    out.push_str("pub(crate) static RULE_DOCS: &[(&str, &str)] = &[\n");
+    for (code, docs) in rule_docs() {
+        writeln!(out, "    ({code:?}, {docs:?}),").unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("pub(crate) static RULE_CONFIG_OPTIONS: &[(&str, &[ConfigOption])] = &[\n");
+    for (name, options) in config_options() {
+        writeln!(out, "    ({name:?}, &[").unwrap();
+        for (option, default, description) in options {
+            writeln!(
+                out,
+                "        ConfigOption {{ name: {option:?}, default: {default:?}, description: \
+                 {description:?} }},"
+            )
+            .unwrap();
+        }
+        out.push_str("    ]),\n");
+    }
+    out.push_str("];\n");
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("rule_docs.rs"), out).unwrap();
+}
+
+/// The doc comment of every rule struct, keyed on the code it's named after.
+fn rule_docs() -> Vec<(String, String)> {
+    let mut docs = Vec::new();
+
+    for group in std::fs::read_dir(RULES_DIR).unwrap() {
+        let group = group.unwrap().path();
+        if !group.is_dir() {
+            continue;
+        }
+
+        for file in std::fs::read_dir(group).unwrap() {
+            let file = file.unwrap().path();
+            let code = file.file_stem().unwrap().to_str().unwrap().to_string();
+            let source = std::fs::read_to_string(&file).unwrap();
+
+            if let Some(doc) = struct_doc(&source, &format!("pub struct Rule{code}")) {
+                docs.push((code, doc));
+            }
+        }
+    }
+
+    docs.sort();
+    docs
+}
+
+/// The doc comment right above `item`, skipping its attributes.
+fn struct_doc(source: &str, item: &str) -> Option<String> {
+    let lines: Vec<_> = source.lines().collect();
+    let idx = lines.iter().position(|line| line.starts_with(item))?;
+
+    let mut doc = Vec::new();
+    for line in lines[..idx].iter().rev() {
+        if line.starts_with("#[") {
+            continue;
+        }
+        match line.strip_prefix("///") {
+            Some(line) => doc.push(line.strip_prefix(' ').unwrap_or(line)),
+            None => break,
+        }
+    }
+
+    doc.reverse();
+    (!doc.is_empty()).then(|| doc.join("\n") + "\n")
+}
+
+/// The name, default and description of an option.
+type ConfigOption = (String, String, String);
+
+/// The options of every rule section of the default config, with their
+/// defaults and the comments above them.
+fn config_options() -> Vec<(String, Vec<ConfigOption>)> {
+    let config = std::fs::read_to_string(DEFAULT_CONFIG).unwrap();
+
+    let mut sections: Vec<(String, Vec<_>)> = Vec::new();
+    let mut in_rule_section = false;
+    let mut comment = Vec::new();
+
+    for line in config.lines().map(str::trim) {
+        if let Some(section) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            in_rule_section = match section.strip_prefix("sqlfluff:rules:") {
+                Some(name) => {
+                    sections.push((name.to_string(), Vec::new()));
+                    true
+                }
+                None => false,
+            };
+            comment.clear();
+        } else if let Some(line) = line.strip_prefix('#') {
+            comment.push(line.trim());
+        } else {
+            if let (Some((key, value)), true) = (line.split_once('='), in_rule_section) {
+                let options = &mut sections.last_mut().unwrap().1;
+                options.push((key.trim().to_string(), value.trim().to_string(), comment.join(" ")));
+            }
+            comment.clear();
+        }
+    }
+
+    sections
+}
@@ -3,7 +3,7 @@ use std::mem::take;
 use ahash::AHashMap;
 
 use crate::cli::formatters::OutputStreamFormatter;
-use crate::core::config::FluffConfig;
+use crate::core::config::{FluffConfig, Value};
 use crate::core::dialects::init::dialect_selector;
 use crate::core::errors::{SQLFluffUserError, SQLLintError};
 use crate::core::linter::linter::Linter;
@@ -37,6 +37,7 @@ pub fn get_simple_config(
         .map_err(|err| SQLFluffUserError::new(format!("Error loading config: {:?}", err)))
 }
 
+/// Lint a SQL string, returning only the violations of the given rules.
 pub fn lint(
     sql: String,
     dialect: String,
@@ -47,7 +48,7 @@ pub fn lint(
     lint_with_formatter(sql, dialect, rules, exclude_rules, config_path, None)
 }
 
-/// Lint a SQL string.
+/// Lint a SQL string, returning only the violations of the given rules.
 pub fn lint_with_formatter(
     sql: String,
     dialect: String,
@@ -55,6 +56,22 @@ pub fn lint_with_formatter(
     exclude_rules: Option<Vec<String>>,
     config_path: Option<String>,
     formatter: Option<OutputStreamFormatter>,
+) -> Result<Vec<SQLLintError>, SQLFluffUserError> {
+    let mut violations =
+        lint_with_parse_errors(sql, dialect, rules, exclude_rules, config_path, formatter)?;
+    violations.retain(|violation| violation.rule.is_some());
+    Ok(violations)
+}
+
+/// Lint a SQL string, returning the parsing errors (with the code `PRS`)
+/// alongside the violations of the given rules.
+pub fn lint_with_parse_errors(
+    sql: String,
+    dialect: String,
+    rules: Vec<ErasedRule>,
+    exclude_rules: Option<Vec<String>>,
+    config_path: Option<String>,
+    formatter: Option<OutputStreamFormatter>,
 ) -> Result<Vec<SQLLintError>, SQLFluffUserError> {
     let cfg = get_simple_config(dialect.into(), None, exclude_rules, config_path)?;
 
@@ -62,12 +79,19 @@ pub fn lint_with_formatter(
     linter.formatter = formatter;
 
     let mut result = linter.lint_string_wrapped(sql, None, None, rules);
-
     Ok(take(&mut result.paths[0].files[0].violations))
 }
 
+/// Fix a SQL string with the given rules. The fixes are applied even where
+/// parts of the string could not be parsed, as there is no way to report
+/// them being held back.
 pub fn fix(sql: String, rules: Vec<ErasedRule>) -> String {
-    let cfg = get_simple_config(Some("ansi".into()), None, None, None).unwrap();
+    let mut cfg = get_simple_config(Some("ansi".into()), None, None, None).unwrap();
+    cfg.raw
+        .get_mut("core")
+        .and_then(Value::as_map_mut)
+        .unwrap()
+        .insert("fix_even_unparsable".into(), Value::Bool(true));
     let mut linter = Linter::new(cfg, None, None);
     let result = linter.lint_string_wrapped(sql, None, Some(true), rules);
     result.paths[0].files[0].fix_string()
@@ -75,7 +99,7 @@ pub fn fix(sql: String, rules: Vec<ErasedRule>) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::fix;
+    use super::{fix, lint, lint_with_parse_errors};
     use crate::core::rules::base::Erased;
     use crate::rules::l001::RuleL001;
 
@@ -87,6 +111,19 @@ mod tests {
         assert_eq!(sql, "SELECT 1");
     }
 
+    #[test]
+    fn simple_api_parse_errors_opt_in() {
+        let sql = "SELECT a FROM t;\nNOT VALID SQL;\n";
+        let rules = || vec![RuleL001::default().erased()];
+
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+        assert!(violations.iter().all(|violation| violation.rule.is_some()));
+
+        let violations =
+            lint_with_parse_errors(sql.into(), "ansi".into(), rules(), None, None, None).unwrap();
+        assert!(violations.iter().any(|violation| violation.rule.is_none()));
+    }
+
     #[test]
     #[ignore]
     fn test_api_fix_string_specific() {
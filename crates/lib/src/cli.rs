@@ -1 +1,5 @@
+pub mod checkstyle;
 pub mod formatters;
+pub mod junit;
+pub mod sarif;
+mod xml;
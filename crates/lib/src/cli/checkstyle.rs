@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use super::xml::escape;
+use crate::core::linter::linting_result::LintingResult;
+
+/// The violations in `result` as a Checkstyle XML report.
+pub fn checkstyle(result: &LintingResult) -> String {
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    report.push_str("<checkstyle version=\"4.3\">\n");
+
+    for file in result.paths.iter().flat_map(|path| &path.files) {
+        _ = writeln!(report, r#"  <file name="{}">"#, escape(&file.path));
+
+        for violation in &file.violations {
+            _ = writeln!(
+                report,
+                r#"    <error line="{}" column="{}" endLine="{}" endColumn="{}" severity="{}" message="{}" source="sqruff.{}"/>"#,
+                violation.line_no,
+                file.column(violation.line_no, violation.line_pos),
+                violation.end_line_no,
+                file.column(violation.end_line_no, violation.end_line_pos),
+                if violation.warning { "warning" } else { "error" },
+                escape(&violation.description),
+                escape(violation.rule_code()),
+            );
+        }
+
+        report.push_str("  </file>\n");
+    }
+
+    report.push_str("</checkstyle>\n");
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::checkstyle;
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::linter::linter::Linter;
+
+    #[test]
+    fn test__checkstyle__report() {
+        let config = FluffConfig::new(
+            [("core".into(), Value::Map([("rules".into(), Value::String("LT01".into()))].into()))]
+                .into(),
+            None,
+            None,
+        );
+        let mut linter = Linter::new(config, None, None);
+        let rules = linter.get_rulepack().rules();
+        let result =
+            linter.lint_string_wrapped("SELECT 'é' ,b\n".into(), Some("a.sql".into()), None, rules);
+
+        assert_eq!(
+            checkstyle(&result),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="4.3">
+  <file name="a.sql">
+    <error line="1" column="11" endLine="1" endColumn="12" severity="error" message="Unexpected whitespace before &quot;,&quot;." source="sqruff.LT01"/>
+    <error line="1" column="13" endLine="1" endColumn="14" severity="error" message="Expected single whitespace between &quot;,&quot; and &quot;b&quot;." source="sqruff.LT01"/>
+  </file>
+</checkstyle>
+"#
+        );
+    }
+}
@@ -57,6 +57,9 @@ pub struct OutputStreamFormatter {
     filter_empty: bool,
     verbosity: i32,
     output_line_length: usize,
+    /// Where warnings go instead of the output stream, if they're to be seen
+    /// when the rest of the output isn't.
+    warning_stream: Option<Box<dyn Write>>,
     pub has_fail: Cell<bool>,
 }
 
@@ -68,10 +71,17 @@ impl OutputStreamFormatter {
             filter_empty: true,
             verbosity: 0,
             output_line_length: 80,
+            warning_stream: None,
             has_fail: false.into(),
         }
     }
 
+    /// Write warnings to `stream` rather than the output stream.
+    pub fn warnings_to(mut self, stream: Box<dyn Write>) -> Self {
+        self.warning_stream = Some(stream);
+        self
+    }
+
     fn should_produce_plain_output(nocolor: bool) -> bool {
         nocolor || !std::io::stdout().is_terminal()
     }
@@ -164,6 +174,30 @@ impl OutputStreamFormatter {
         self.dispatch(&s);
     }
 
+    pub fn dispatch_file_skipped(&mut self, fname: &str, reason: &str) {
+        if self.verbosity < 0 {
+            return;
+        }
+
+        let mut text = self.format_filename(fname, Status::Skip);
+        text.push('\n');
+        text.push_str(reason);
+        text.push('\n');
+
+        self.dispatch(&text);
+    }
+
+    /// Tell the user about a problem which isn't a violation, such as a
+    /// mistake in the config. Warnings are shown at any verbosity.
+    pub fn dispatch_warning(&mut self, message: &str) {
+        let warning = self.colorize("WARNING", AnsiColor::Yellow.on_default());
+        let text = format!("{warning}: {message}\n");
+        match &mut self.warning_stream {
+            Some(stream) => _ = stream.write_all(text.as_bytes()),
+            None => self.dispatch(&text),
+        }
+    }
+
     fn colorize<'a>(&self, s: &'a str, style: Style) -> Cow<'a, str> {
         Self::colorize_helper(self.plain_output, s, style)
     }
@@ -183,6 +217,7 @@ impl OutputStreamFormatter {
 
         let color = match status {
             Status::Pass | Status::Fixed => AnsiColor::Green,
+            Status::Skip => AnsiColor::Yellow,
             Status::Fail | Status::Error => {
                 self.has_fail.set(true);
                 AnsiColor::Red
@@ -266,6 +301,12 @@ pub trait IntoStatus {
     fn into_status(self) -> Status;
 }
 
+impl IntoStatus for Status {
+    fn into_status(self) -> Status {
+        self
+    }
+}
+
 impl IntoStatus for bool {
     fn into_status(self) -> Status {
         if self { Status::Pass } else { Status::Fail }
@@ -285,6 +326,7 @@ pub enum Status {
     Fixed,
     Fail,
     Error,
+    Skip,
 }
 
 impl Status {
@@ -294,6 +336,7 @@ impl Status {
             Status::Fixed => "FIXED",
             Status::Fail => "FAIL",
             Status::Error => "ERROR",
+            Status::Skip => "SKIP",
         }
     }
 }
@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+use super::xml::escape;
+use crate::core::linter::linting_result::LintingResult;
+
+/// The violations in `result` as a JUnit XML report, for CI servers.
+///
+/// Each linted path is a test suite with a test case per file, which fails
+/// once for every violation in the file.
+pub fn junit(result: &LintingResult) -> String {
+    let files = result.paths.iter().flat_map(|path| &path.files);
+    let tests = files.clone().count();
+    let failures = files.filter(|file| !file.violations.is_empty()).count();
+
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    _ = writeln!(report, r#"<testsuites name="sqruff" tests="{tests}" failures="{failures}">"#);
+
+    for path in &result.paths {
+        let name = escape(&path.path);
+        let tests = path.files.len();
+        let failures = path.files.iter().filter(|file| !file.violations.is_empty()).count();
+
+        _ = writeln!(
+            report,
+            r#"  <testsuite name="{name}" tests="{tests}" failures="{failures}">"#
+        );
+
+        for file in &path.files {
+            let file_name = escape(&file.path);
+
+            if file.violations.is_empty() {
+                _ = writeln!(report, r#"    <testcase classname="{name}" name="{file_name}"/>"#);
+                continue;
+            }
+
+            _ = writeln!(report, r#"    <testcase classname="{name}" name="{file_name}">"#);
+            for violation in &file.violations {
+                _ = writeln!(
+                    report,
+                    r#"      <failure type="{code}" message="{code}: {description}">Line {line_no}, Position {column} to Line {end_line_no}, Position {end_column}: {description}</failure>"#,
+                    code = escape(violation.rule_code()),
+                    description = escape(&violation.description),
+                    line_no = violation.line_no,
+                    column = file.column(violation.line_no, violation.line_pos),
+                    end_line_no = violation.end_line_no,
+                    end_column = file.column(violation.end_line_no, violation.end_line_pos),
+                );
+            }
+            report.push_str("    </testcase>\n");
+        }
+
+        report.push_str("  </testsuite>\n");
+    }
+
+    report.push_str("</testsuites>\n");
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::junit;
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::linter::linter::Linter;
+
+    #[test]
+    fn test__junit__report() {
+        let config = FluffConfig::new(
+            [("core".into(), Value::Map([("rules".into(), Value::String("LT01".into()))].into()))]
+                .into(),
+            None,
+            None,
+        );
+        let mut linter = Linter::new(config, None, None);
+        let rules = linter.get_rulepack().rules();
+
+        let mut result = linter.lint_string_wrapped(
+            "SELECT 'é' ,b\n".into(),
+            Some("a.sql".into()),
+            None,
+            rules.clone(),
+        );
+        let mut passing =
+            linter.lint_string_wrapped("SELECT a\n".into(), Some("b.sql".into()), None, rules);
+        result.paths[0].add(passing.paths[0].files.pop().unwrap());
+
+        assert_eq!(
+            junit(&result),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="sqruff" tests="2" failures="1">
+  <testsuite name="a.sql" tests="2" failures="1">
+    <testcase classname="a.sql" name="a.sql">
+      <failure type="LT01" message="LT01: Unexpected whitespace before &quot;,&quot;.">Line 1, Position 11 to Line 1, Position 12: Unexpected whitespace before &quot;,&quot;.</failure>
+      <failure type="LT01" message="LT01: Expected single whitespace between &quot;,&quot; and &quot;b&quot;.">Line 1, Position 13 to Line 1, Position 14: Expected single whitespace between &quot;,&quot; and &quot;b&quot;.</failure>
+    </testcase>
+    <testcase classname="a.sql" name="b.sql"/>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
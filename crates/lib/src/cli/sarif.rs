@@ -0,0 +1,160 @@
+use std::ops::Range;
+
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+use crate::core::linter::common::TextEdit;
+use crate::core::linter::linter::Linter;
+use crate::core::linter::linting_result::LintingResult;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The violations in `result` as a SARIF 2.1.0 log with a single run, for
+/// code scanning tools.
+///
+/// Violations which can be fixed on their own come with their fix, which is
+/// why this needs the `linter` which produced them.
+pub fn sarif(linter: &Linter, result: &LintingResult) -> Value {
+    let ruleset = linter.get_ruleset();
+
+    let rules = ruleset
+        .register
+        .values()
+        .map(|manifest| {
+            json!({
+                "id": manifest.code(),
+                "name": manifest.name(),
+                "shortDescription": { "text": manifest.description() },
+                "properties": { "tags": manifest.groups },
+            })
+        })
+        .collect_vec();
+
+    let mut results = Vec::new();
+    for file in result.paths.iter().flat_map(|path| &path.files) {
+        let uri = file.path.replace('\\', "/");
+        let source = file.source_string();
+        // Violations point into the source as it was templated, with its
+        // newlines normalised.
+        let source_str = &file.templated_file.source_str;
+
+        for violation in &file.violations {
+            let mut entry = json!({
+                "ruleId": violation.rule_code(),
+                "level": if violation.warning { "warning" } else { "error" },
+                "message": { "text": violation.description },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": uri },
+                        "region": region(source_str, violation.source_slice.clone()),
+                    },
+                }],
+            });
+
+            if let Some(index) = ruleset.register.get_index_of(violation.rule_code()) {
+                entry["ruleIndex"] = index.into();
+            }
+
+            if let Some(fixed) = linter.fix_violation(file, violation) {
+                let edit = TextEdit::between(&source, &fixed);
+
+                entry["fixes"] = json!([{
+                    "description": { "text": format!("Fix {}", violation.rule_code()) },
+                    "artifactChanges": [{
+                        "artifactLocation": { "uri": uri },
+                        "replacements": [{
+                            "deletedRegion": region(&source, edit.range),
+                            "insertedContent": { "text": edit.replacement },
+                        }],
+                    }],
+                }]);
+            }
+
+            results.push(entry);
+        }
+    }
+
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "sqruff",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    })
+}
+
+/// The region of `source` covered by a byte range.
+fn region(source: &str, range: Range<usize>) -> Value {
+    let (start_line, start_column) = line_and_column(source, range.start);
+    let (end_line, end_column) = line_and_column(source, range.end);
+
+    json!({
+        "startLine": start_line,
+        "startColumn": start_column,
+        "endLine": end_line,
+        "endColumn": end_column,
+    })
+}
+
+/// The 1-based line and column of a byte offset into `source`.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::sarif;
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::linter::linter::Linter;
+
+    #[test]
+    fn test__sarif__results() {
+        let config = FluffConfig::new(
+            [("core".into(), Value::Map([("rules".into(), Value::String("LT01".into()))].into()))]
+                .into(),
+            None,
+            None,
+        );
+        let mut linter = Linter::new(config, None, None);
+        let rules = linter.get_rulepack().rules();
+        let result = linter.lint_string_wrapped("SELECT a  ,b FROM t\n".into(), None, None, rules);
+
+        let log = sarif(&linter, &result);
+        let run = &log["runs"][0];
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert!(rules.iter().any(|rule| rule["id"] == "LT01" && rule["name"] == "layout.spacing"));
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+
+        let first = &results[0];
+        assert_eq!(first["ruleId"], "LT01");
+        assert_eq!(first["level"], "error");
+        assert_eq!(rules[first["ruleIndex"].as_u64().unwrap() as usize]["id"], "LT01");
+        assert_eq!(
+            first["locations"][0]["physicalLocation"]["region"],
+            json!({ "startLine": 1, "startColumn": 9, "endLine": 1, "endColumn": 11 })
+        );
+        assert_eq!(
+            first["fixes"][0]["artifactChanges"][0]["replacements"][0],
+            json!({
+                "deletedRegion": { "startLine": 1, "startColumn": 9, "endLine": 1, "endColumn": 11 },
+                "insertedContent": { "text": "" },
+            })
+        );
+    }
+}
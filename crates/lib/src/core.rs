@@ -1,5 +1,6 @@
 pub mod config;
 pub mod dialects;
+pub mod encoding;
 pub mod enums;
 pub mod errors;
 pub mod linter;
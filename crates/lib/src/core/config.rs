@@ -8,7 +8,9 @@ use itertools::Itertools;
 
 use super::dialects::base::Dialect;
 use crate::core::dialects::init::{dialect_readout, dialect_selector, get_default_dialect};
+use crate::core::encoding::FileEncoding;
 use crate::core::errors::SQLFluffUserError;
+use crate::core::linter::common::LineEnding;
 
 #[derive(Clone, Debug)]
 pub struct RemovedConfig<'a> {
@@ -236,7 +238,14 @@ impl FluffConfig {
         let config =
             loader.load_config_up_to_path(".", extra_config_path.clone(), ignore_local_config);
 
-        Ok(FluffConfig::new(config, extra_config_path, None))
+        let config = FluffConfig::new(config, extra_config_path, None);
+        if let Some(error) = config.verify_encoding() {
+            return Err(error);
+        }
+        if let Some(error) = config.verify_line_ending() {
+            return Err(error);
+        }
+        Ok(config)
     }
 
     pub fn from_kwargs(
@@ -286,6 +295,27 @@ command. Available dialects: {}",
         )))
     }
 
+    /// Check that the `encoding` is `autodetect` or one which is known.
+    pub fn verify_encoding(&self) -> Option<SQLFluffUserError> {
+        let encoding = self.get("encoding", "core").as_string()?;
+        if encoding.eq_ignore_ascii_case("autodetect")
+            || FileEncoding::for_label(encoding).is_some()
+        {
+            return None;
+        }
+
+        Some(SQLFluffUserError::new(format!(
+            "Unknown encoding {encoding:?} in the config. It can either be autodetect or a valid \
+             encoding e.g. utf-8, utf-8-sig"
+        )))
+    }
+
+    /// Check that the `line_ending` is `auto` or one which is known.
+    pub fn verify_line_ending(&self) -> Option<SQLFluffUserError> {
+        let line_ending = self.get("line_ending", "core").as_string()?;
+        LineEnding::from_config(line_ending).err()
+    }
+
     pub fn get_dialect(&self) -> &Dialect {
         &self.dialect
     }
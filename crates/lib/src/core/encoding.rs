@@ -0,0 +1,209 @@
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252};
+
+/// The encoding of a source file, and whether it started with a byte order
+/// mark, so that fixed files can be written back the way they were read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileEncoding {
+    encoding: &'static Encoding,
+    bom: bool,
+}
+
+impl Default for FileEncoding {
+    fn default() -> Self {
+        Self { encoding: UTF_8, bom: false }
+    }
+}
+
+impl FileEncoding {
+    /// Look up an encoding by name, e.g. `utf-8`, `utf-8-sig` or `latin-1`.
+    pub fn for_label(label: &str) -> Option<FileEncoding> {
+        let label = label.trim().to_lowercase();
+        match label.as_str() {
+            "utf-8-sig" | "utf8-sig" => Some(Self { encoding: UTF_8, bom: true }),
+            // Python spelling, which is not one of the WHATWG labels.
+            "latin-1" | "latin1" => Some(Self { encoding: WINDOWS_1252, bom: false }),
+            _ => {
+                Encoding::for_label(label.as_bytes()).map(|encoding| Self { encoding, bom: false })
+            }
+        }
+    }
+
+    /// Work out the encoding of `bytes` from the `encoding` config value,
+    /// which is either a label or `autodetect`.
+    ///
+    /// Auto-detection looks for a byte order mark, then for UTF-16 without
+    /// one, then falls back to Latin-1 if the bytes aren't valid UTF-8. Labels
+    /// are checked when the config is loaded, so an unknown one is detected
+    /// too.
+    pub fn detect(bytes: &[u8], config_encoding: &str) -> FileEncoding {
+        if let Some(mut encoding) = Self::for_label(config_encoding) {
+            encoding.bom |= encoding.bom_len(bytes) > 0;
+            return encoding;
+        }
+
+        if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+            return Self { encoding, bom: true };
+        }
+
+        if let Some(encoding) = Self::sniff_utf16(bytes) {
+            return Self { encoding, bom: false };
+        }
+
+        if std::str::from_utf8(bytes).is_ok() {
+            Self::default()
+        } else {
+            Self { encoding: WINDOWS_1252, bom: false }
+        }
+    }
+
+    /// UTF-16 text without a byte order mark has a zero byte in every other
+    /// position for ASCII characters, which SQL is mostly made of.
+    fn sniff_utf16(bytes: &[u8]) -> Option<&'static Encoding> {
+        if bytes.len() < 2 || bytes.len() % 2 != 0 {
+            return None;
+        }
+
+        let pairs = bytes.len() / 2;
+        let even_zeros = bytes.iter().step_by(2).filter(|&&byte| byte == 0).count();
+        let odd_zeros = bytes.iter().skip(1).step_by(2).filter(|&&byte| byte == 0).count();
+
+        if odd_zeros * 10 >= pairs * 9 && even_zeros == 0 {
+            Some(UTF_16LE)
+        } else if even_zeros * 10 >= pairs * 9 && odd_zeros == 0 {
+            Some(UTF_16BE)
+        } else {
+            None
+        }
+    }
+
+    fn bom_len(&self, bytes: &[u8]) -> usize {
+        match Encoding::for_bom(bytes) {
+            Some((encoding, len)) if encoding == self.encoding => len,
+            _ => 0,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        let name = self.encoding.name().to_lowercase();
+        if self.bom { format!("{name}-sig") } else { name }
+    }
+
+    pub fn has_bom(&self) -> bool {
+        self.bom
+    }
+
+    /// Decode `bytes`, dropping the byte order mark if there is one.
+    ///
+    /// Gives `None` if they aren't valid in this encoding, rather than
+    /// replacing the malformed sequences, which fixing would write back.
+    pub fn decode(&self, bytes: &[u8]) -> Option<String> {
+        let bytes = &bytes[self.bom_len(bytes)..];
+        self.encoding
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            .map(|string| string.into_owned())
+    }
+
+    /// Encode `string`, adding back the byte order mark if the file had one.
+    pub fn encode(&self, string: &str) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(string.len() + 3);
+
+        // `encoding_rs` only encodes to UTF-8 for the UTF-16 encodings.
+        if self.encoding == UTF_16LE || self.encoding == UTF_16BE {
+            let little_endian = self.encoding == UTF_16LE;
+            let units = self.bom.then_some(0xFEFF).into_iter().chain(string.encode_utf16());
+            for unit in units {
+                let unit = if little_endian { unit.to_le_bytes() } else { unit.to_be_bytes() };
+                bytes.extend(unit);
+            }
+            return bytes;
+        }
+
+        if self.bom && self.encoding == UTF_8 {
+            bytes.extend(b"\xEF\xBB\xBF");
+        }
+        bytes.extend_from_slice(&self.encoding.encode(string).0);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileEncoding;
+    use crate::core::config::{FluffConfig, Value};
+
+    #[test]
+    fn test__encoding__autodetect_utf8() {
+        let encoding = FileEncoding::detect("select 'é'\n".as_bytes(), "autodetect");
+        assert_eq!(encoding.name(), "utf-8");
+        assert_eq!(encoding.decode("select 'é'\n".as_bytes()).unwrap(), "select 'é'\n");
+    }
+
+    #[test]
+    fn test__encoding__autodetect_utf8_bom() {
+        let bytes = b"\xEF\xBB\xBFselect 1\n";
+        let encoding = FileEncoding::detect(bytes, "autodetect");
+
+        assert_eq!(encoding.name(), "utf-8-sig");
+        assert_eq!(encoding.decode(bytes).unwrap(), "select 1\n");
+        assert_eq!(encoding.encode("select 1\n"), bytes);
+    }
+
+    #[test]
+    fn test__encoding__autodetect_utf16() {
+        let with_bom: &[u8] = b"\xFF\xFEs\x00e\x00l\x00\n\x00";
+        let encoding = FileEncoding::detect(with_bom, "autodetect");
+        assert_eq!(encoding.name(), "utf-16le-sig");
+        assert_eq!(encoding.decode(with_bom).unwrap(), "sel\n");
+        assert_eq!(encoding.encode("sel\n"), with_bom);
+
+        let without_bom: &[u8] = b"\x00s\x00e\x00l\x00\n";
+        let encoding = FileEncoding::detect(without_bom, "autodetect");
+        assert_eq!(encoding.name(), "utf-16be");
+        assert_eq!(encoding.decode(without_bom).unwrap(), "sel\n");
+        assert_eq!(encoding.encode("sel\n"), without_bom);
+    }
+
+    #[test]
+    fn test__encoding__autodetect_latin1() {
+        let bytes = b"select '\xE9'\n";
+        let encoding = FileEncoding::detect(bytes, "autodetect");
+
+        assert_eq!(encoding.name(), "windows-1252");
+        assert_eq!(encoding.decode(bytes).unwrap(), "select 'é'\n");
+        assert_eq!(encoding.encode("select 'é'\n"), bytes);
+    }
+
+    #[test]
+    fn test__encoding__explicit() {
+        let bytes = b"select '\xE9'\n";
+        assert_eq!(FileEncoding::detect(bytes, "latin-1").decode(bytes).unwrap(), "select 'é'\n");
+
+        let encoding = FileEncoding::detect(b"\xEF\xBB\xBFselect 1", "utf-8");
+        assert!(encoding.has_bom());
+        assert_eq!(FileEncoding::detect(b"select 1", "utf-8-sig").encode("a"), b"\xEF\xBB\xBFa");
+    }
+
+    #[test]
+    fn test__encoding__unknown() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("encoding".into(), Value::String("utf-9".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        );
+        let error = config.verify_encoding().unwrap();
+        assert!(error.to_string().starts_with("Unknown encoding \"utf-9\""));
+
+        let encoding = FileEncoding::detect(b"select 1", "utf-9");
+        assert_eq!(encoding.name(), "utf-8");
+    }
+
+    #[test]
+    fn test__encoding__explicit_malformed() {
+        let bytes = b"select '\xE9'\n";
+        assert_eq!(FileEncoding::detect(bytes, "utf-8").decode(bytes), None);
+    }
+}
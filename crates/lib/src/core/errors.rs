@@ -1,11 +1,11 @@
 // use super::pos::PosMarker;
 
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Range};
 
 use fancy_regex::Regex;
 
 use super::parser::segments::base::ErasedSegment;
-use super::rules::base::ErasedRule;
+use super::rules::base::{ErasedRule, LintFix};
 use crate::core::parser::markers::PositionMarker;
 use crate::helpers::Config;
 
@@ -26,6 +26,11 @@ pub struct SQLBaseError {
     pub warning: bool,
     pub line_no: usize,
     pub line_pos: usize,
+    /// The line and position just past the end of the violation.
+    pub end_line_no: usize,
+    pub end_line_pos: usize,
+    /// The byte range of the violation in the source.
+    pub source_slice: Range<usize>,
     pub description: String,
     pub rule_code: String,
     pub rule: Option<ErasedRule>,
@@ -46,6 +51,9 @@ impl SQLBaseError {
             warning: false,
             line_no: 0,
             line_pos: 0,
+            end_line_no: 0,
+            end_line_pos: 0,
+            source_slice: 0..0,
             rule_code: "????".into(),
             rule: None,
         }
@@ -56,10 +64,9 @@ impl SQLBaseError {
     }
 
     pub fn set_position_marker(&mut self, position_marker: PositionMarker) {
-        let (line_no, line_pos) = position_marker.source_position();
-
-        self.line_no = line_no;
-        self.line_pos = line_pos;
+        (self.line_no, self.line_pos) = position_marker.source_position();
+        (self.end_line_no, self.end_line_pos) = position_marker.source_end_position();
+        self.source_slice = position_marker.source_slice;
     }
 
     pub fn desc(&self) -> &str {
@@ -193,9 +200,11 @@ impl SqlError for SQLBaseError {
 //     }
 // }
 //
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct SQLLintError {
     base: SQLBaseError,
+    /// The fixes which would resolve this violation.
+    pub fixes: Vec<LintFix>,
 }
 
 impl SQLLintError {
@@ -205,10 +214,19 @@ impl SQLLintError {
                 this.description = description.into();
                 this.set_position_marker(segment.get_position_marker().unwrap());
             }),
+            fixes: Vec::new(),
         }
     }
 }
 
+/// Violations are compared on where they are and what they say, not on how
+/// they would be fixed.
+impl PartialEq for SQLLintError {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
 impl Deref for SQLLintError {
     type Target = SQLBaseError;
 
@@ -223,6 +241,14 @@ impl DerefMut for SQLLintError {
     }
 }
 
+/// Errors from earlier stages, such as parsing, are reported alongside the
+/// linting errors.
+impl From<SQLBaseError> for SQLLintError {
+    fn from(base: SQLBaseError) -> Self {
+        Self { base, fixes: Vec::new() }
+    }
+}
+
 impl From<SQLLintError> for SQLBaseError {
     fn from(mut value: SQLLintError) -> Self {
         if let Some(rule) = &value.rule {
@@ -277,7 +303,6 @@ impl SqlError for SQLTemplaterError {
 /// An error which should be fed back to the user.
 #[derive(Debug)]
 pub struct SQLFluffUserError {
-    #[allow(dead_code)]
     value: String,
 }
 
@@ -287,6 +312,12 @@ impl SQLFluffUserError {
     }
 }
 
+impl std::fmt::Display for SQLFluffUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
 // Not from SQLFluff but translates Python value error
 #[derive(Debug)]
 pub struct ValueError {
@@ -328,18 +359,15 @@ impl SQLParseError {
 
 impl From<SQLParseError> for SQLBaseError {
     fn from(value: SQLParseError) -> Self {
-        let (mut line_no, mut line_pos) = Default::default();
-
         let pos_marker = value.segment.and_then(|segment| segment.get_position_marker());
 
-        if let Some(pos_marker) = pos_marker {
-            (line_no, line_pos) = pos_marker.source_position();
-        }
-
         Self::new().config(|this| {
+            if let Some(pos_marker) = pos_marker {
+                this.set_position_marker(pos_marker);
+            }
             this.fatal = true;
-            this.line_no = line_no;
-            this.line_pos = line_pos;
+            this.description = value.description;
+            this.rule_code = "PRS".into();
         })
     }
 }
@@ -356,9 +384,9 @@ impl SQLLexError {
     }
 }
 
+/// An error returned to skip a file, e.g. because it is too large.
 #[derive(Debug)]
 pub struct SQLFluffSkipFile {
-    #[allow(dead_code)]
     value: String,
 }
 
@@ -367,3 +395,9 @@ impl SQLFluffSkipFile {
         SQLFluffSkipFile { value }
     }
 }
+
+impl std::fmt::Display for SQLFluffSkipFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cache;
 pub mod common;
 pub mod linted_dir;
 pub mod linted_file;
@@ -0,0 +1,351 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::core::config::{FluffConfig, Value};
+use crate::core::errors::{SQLBaseError, SQLLintError};
+use crate::core::rules::base::{RuleManifest, RulePack};
+use crate::helpers::Config;
+
+/// Lint results stored on disk, so that files which haven't changed since
+/// they were last linted can be skipped.
+///
+/// Results are keyed on the path and content of the file, the config, the
+/// dialect, the rules from outside of sqruff and the version of sqruff, so
+/// changing any of them means linting again.
+#[derive(Debug, Clone)]
+pub struct LintCache {
+    dir: PathBuf,
+    /// The hash of every part of the key besides the file content.
+    seed: u64,
+}
+
+/// What's stored in the cache for a file.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    header: CacheHeader,
+    violations: Vec<CachedViolation>,
+}
+
+/// How a cache file was written. Files written any other way, such as by
+/// another version of sqruff, are ignored.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CacheHeader {
+    version: String,
+    hash: String,
+}
+
+impl CacheHeader {
+    fn current() -> Self {
+        Self { version: env!("CARGO_PKG_VERSION").into(), hash: StableHasher::ALGORITHM.into() }
+    }
+}
+
+/// A violation as it's stored in the cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedViolation {
+    line_no: usize,
+    line_pos: usize,
+    end_line_no: usize,
+    end_line_pos: usize,
+    source_slice: Range<usize>,
+    description: String,
+    rule_code: String,
+    fatal: bool,
+    ignore: bool,
+    warning: bool,
+}
+
+impl LintCache {
+    pub const DEFAULT_DIR: &'static str = ".sqruff_cache";
+
+    pub fn new(dir: impl Into<PathBuf>, config: &FluffConfig) -> Self {
+        let mut hasher = StableHasher::new();
+        hasher.write_str(env!("CARGO_PKG_VERSION"));
+        hasher.write_value(config.get("dialect", "core"));
+        for (key, value) in config.raw.iter().sorted_by_key(|(key, _)| *key) {
+            hasher.write_str(key);
+            hasher.write_value(value);
+        }
+
+        Self { dir: dir.into(), seed: hasher.finish() }
+    }
+
+    /// Also key results on the rules which come from outside of sqruff and
+    /// its config, as they may change independently of both.
+    pub fn with_rules<'a>(mut self, manifests: impl IntoIterator<Item = &'a RuleManifest>) -> Self {
+        let mut hasher = StableHasher::new();
+        hasher.write_u64(self.seed);
+        for manifest in manifests {
+            hasher.write_str(manifest.code());
+            hasher.write_str(manifest.name());
+            hasher.write_str(manifest.description());
+            hasher.write_strs(manifest.groups);
+            hasher.write_strs(&manifest.aliases);
+            hasher.write_str(manifest.rule_class.config_ref());
+            hasher.write_str(manifest.rule_class.long_description());
+        }
+
+        self.seed = hasher.finish();
+        self
+    }
+
+    /// The key of the results for the file at `path` with the given content.
+    ///
+    /// The path is part of the key as the config which applies to a file
+    /// depends on where it is.
+    pub fn key(&self, path: &Path, source: &[u8]) -> String {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+        let mut hasher = StableHasher::new();
+        hasher.write_u64(self.seed);
+        hasher.write_bytes(path.as_os_str().as_encoded_bytes());
+        hasher.write_bytes(source);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// The violations cached under `key`, with their rules looked up in
+    /// `rule_pack`.
+    pub fn get(&self, key: &str, rule_pack: &RulePack) -> Option<Vec<SQLLintError>> {
+        let contents = std::fs::read(self.path(key)).ok()?;
+        let file: CacheFile = serde_json::from_slice(&contents).ok()?;
+        if file.header != CacheHeader::current() {
+            return None;
+        }
+        let violations = file.violations;
+
+        let violations = violations.into_iter().map(|violation| {
+            let rule = rule_pack.rules.iter().find(|rule| rule.code() == violation.rule_code);
+
+            SQLBaseError::new()
+                .config(|this| {
+                    this.line_no = violation.line_no;
+                    this.line_pos = violation.line_pos;
+                    this.end_line_no = violation.end_line_no;
+                    this.end_line_pos = violation.end_line_pos;
+                    this.source_slice = violation.source_slice;
+                    this.description = violation.description;
+                    this.rule_code = violation.rule_code;
+                    this.rule = rule.cloned();
+                    this.fatal = violation.fatal;
+                    this.ignore = violation.ignore;
+                    this.warning = violation.warning;
+                })
+                .into()
+        });
+
+        Some(violations.collect())
+    }
+
+    /// Store `violations` under `key`.
+    ///
+    /// Failing to serialize or write the cache isn't an error, the file is
+    /// just linted again next time.
+    pub fn put(&self, key: &str, violations: &[SQLLintError]) {
+        let violations = violations
+            .iter()
+            .map(|violation| CachedViolation {
+                line_no: violation.line_no,
+                line_pos: violation.line_pos,
+                end_line_no: violation.end_line_no,
+                end_line_pos: violation.end_line_pos,
+                source_slice: violation.source_slice.clone(),
+                description: violation.description.clone(),
+                rule_code: violation.rule_code().to_string(),
+                fatal: violation.fatal,
+                ignore: violation.ignore,
+                warning: violation.warning,
+            })
+            .collect_vec();
+
+        let file = CacheFile { header: CacheHeader::current(), violations };
+        let Ok(contents) = serde_json::to_vec(&file) else {
+            return;
+        };
+        let _ = std::fs::create_dir_all(&self.dir)
+            .and_then(|()| std::fs::write(self.path(key), contents));
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is the same algorithm in
+/// every build, so that keys outlive updating Rust.
+///
+/// Everything is written as explicit bytes rather than through `Hash`, whose
+/// implementations may change too.
+struct StableHasher(u64);
+
+impl StableHasher {
+    const ALGORITHM: &'static str = "fnv1a-64";
+
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Prefixed with their length, so that consecutive ones can't run into
+    /// each other.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_u64(bytes.len() as u64);
+        self.write(bytes);
+    }
+
+    fn write_str(&mut self, string: &str) {
+        self.write_bytes(string.as_bytes());
+    }
+
+    fn write_strs(&mut self, strings: &[&str]) {
+        self.write_u64(strings.len() as u64);
+        for string in strings {
+            self.write_str(string);
+        }
+    }
+
+    /// Maps are written in key order, so that the same config hashes the same
+    /// no matter the order it was loaded in.
+    fn write_value(&mut self, value: &Value) {
+        match value {
+            Value::Int(value) => {
+                self.write(&[0]);
+                self.write(&value.to_le_bytes());
+            }
+            Value::Bool(value) => self.write(&[1, u8::from(*value)]),
+            Value::Float(value) => {
+                self.write(&[2]);
+                self.write_u64(value.to_bits());
+            }
+            Value::String(value) => {
+                self.write(&[3]);
+                self.write_str(value);
+            }
+            Value::Map(map) => {
+                self.write(&[4]);
+                self.write_u64(map.len() as u64);
+                for (key, value) in map.iter().sorted_by_key(|(key, _)| *key) {
+                    self.write_str(key);
+                    self.write_value(value);
+                }
+            }
+            Value::Array(values) => {
+                self.write(&[5]);
+                self.write_u64(values.len() as u64);
+                for value in values {
+                    self.write_value(value);
+                }
+            }
+            Value::None => self.write(&[6]),
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+
+    use super::*;
+    use crate::core::config::Value;
+    use crate::core::linter::linter::Linter;
+
+    fn config(rules: &str) -> FluffConfig {
+        FluffConfig::new(
+            [("core".into(), Value::Map([("rules".into(), Value::String(rules.into()))].into()))]
+                .into(),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test__cache__roundtrip() {
+        let dir = TempDir::new("sqruff_cache").unwrap();
+        let config = config("all");
+        let cache = LintCache::new(dir.path(), &config);
+
+        let mut linter = Linter::new(config, None, None);
+        let rule_pack = linter.get_rulepack();
+        let result =
+            linter.lint_string_wrapped("SELECT 1 ,2\n".into(), None, None, rule_pack.rules.clone());
+        let violations = &result.paths[0].files[0].violations;
+        assert!(!violations.is_empty());
+
+        let key = cache.key(Path::new("test.sql"), b"SELECT 1 ,2\n");
+        assert!(cache.get(&key, &rule_pack).is_none());
+
+        cache.put(&key, violations);
+        assert_eq!(&cache.get(&key, &rule_pack).unwrap(), violations);
+    }
+
+    #[test]
+    fn test__cache__key() {
+        let dir = TempDir::new("sqruff_cache").unwrap();
+        let cache = LintCache::new(dir.path(), &config("all"));
+
+        let key = |cache: &LintCache, path: &str, source: &[u8]| cache.key(Path::new(path), source);
+
+        assert_eq!(
+            key(&cache, "a.sql", b"SELECT 1\n"),
+            key(&LintCache::new(dir.path(), &config("all")), "a.sql", b"SELECT 1\n")
+        );
+        assert_ne!(key(&cache, "a.sql", b"SELECT 1\n"), key(&cache, "a.sql", b"SELECT 2\n"));
+        assert_ne!(key(&cache, "a.sql", b"SELECT 1\n"), key(&cache, "b.sql", b"SELECT 1\n"));
+        assert_ne!(
+            key(&cache, "a.sql", b"SELECT 1\n"),
+            key(&LintCache::new(dir.path(), &config("LT01")), "a.sql", b"SELECT 1\n")
+        );
+
+        let ruleset = Linter::new(config("all"), None, None).get_ruleset();
+        let manifests = ruleset.manifests().take(1);
+        assert_ne!(
+            key(&cache, "a.sql", b"SELECT 1\n"),
+            key(&cache.clone().with_rules(manifests), "a.sql", b"SELECT 1\n")
+        );
+    }
+
+    #[test]
+    fn test__cache__stable_hasher() {
+        // Test vectors of FNV-1a.
+        for (input, hash) in
+            [("", 0xcbf29ce484222325), ("a", 0xaf63dc4c8601ec8c), ("foobar", 0x85944171f73967e8)]
+        {
+            let mut hasher = StableHasher::new();
+            hasher.write(input.as_bytes());
+            assert_eq!(hasher.finish(), hash);
+        }
+    }
+
+    #[test]
+    fn test__cache__header() {
+        let dir = TempDir::new("sqruff_cache").unwrap();
+        let config = config("LT01");
+        let cache = LintCache::new(dir.path(), &config);
+        let rule_pack = Linter::new(config, None, None).get_rulepack();
+
+        let key = cache.key(Path::new("test.sql"), b"SELECT 1\n");
+        cache.put(&key, &[]);
+        assert_eq!(cache.get(&key, &rule_pack).unwrap(), []);
+
+        let contents = std::fs::read_to_string(cache.path(&key)).unwrap();
+        let contents = contents.replace(StableHasher::ALGORITHM, "siphash-1-3");
+        std::fs::write(cache.path(&key), contents).unwrap();
+        assert!(cache.get(&key, &rule_pack).is_none());
+    }
+}
@@ -1,7 +1,11 @@
+use std::ops::Range;
+
 use ahash::AHashMap;
 
 use crate::core::config::FluffConfig;
-use crate::core::errors::{SQLBaseError, SQLTemplaterError};
+use crate::core::encoding::FileEncoding;
+use crate::core::errors::{SQLBaseError, SQLFluffUserError, SQLTemplaterError};
+use crate::core::parser::context::ParseStatistics;
 use crate::core::parser::segments::base::ErasedSegment;
 use crate::core::templaters::base::TemplatedFile;
 
@@ -26,6 +30,64 @@ pub struct NoQaDirective {
     action: Option<String>,
 }
 
+/// The line ending style of a source file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+    Cr,
+}
+
+impl LineEnding {
+    /// The most common line ending in `string`, preferring `\n` on ties and
+    /// for strings without any line ending.
+    pub fn detect(string: &str) -> LineEnding {
+        let (mut lf, mut crlf, mut cr) = (0, 0, 0);
+
+        let mut chars = string.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\r' if chars.next_if_eq(&'\n').is_some() => crlf += 1,
+                '\r' => cr += 1,
+                '\n' => lf += 1,
+                _ => {}
+            }
+        }
+
+        if crlf > lf && crlf >= cr {
+            LineEnding::CrLf
+        } else if cr > lf && cr > crlf {
+            LineEnding::Cr
+        } else {
+            LineEnding::Lf
+        }
+    }
+
+    /// Parse the `line_ending` config value. `auto` keeps the ending of each
+    /// file and gives `None`.
+    pub fn from_config(value: &str) -> Result<Option<LineEnding>, SQLFluffUserError> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(None),
+            "lf" => Ok(Some(LineEnding::Lf)),
+            "crlf" => Ok(Some(LineEnding::CrLf)),
+            "cr" => Ok(Some(LineEnding::Cr)),
+            _ => Err(SQLFluffUserError::new(format!(
+                "Unknown line_ending {value:?} in the config. It can be one of auto, lf, crlf or \
+                 cr"
+            ))),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Cr => "\r",
+        }
+    }
+}
+
 /// An object to store the result of a templated file/string.
 ///
 /// This is notable as it's the intermediate state between what happens
@@ -37,7 +99,9 @@ pub struct RenderedFile {
     pub config: FluffConfig,
     pub time_dict: AHashMap<String, f64>,
     pub(crate) f_name: String,
-    pub encoding: String,
+    pub encoding: FileEncoding,
+    /// The line ending to write fixes back with.
+    pub line_ending: LineEnding,
     pub source_str: String,
 }
 
@@ -55,5 +119,44 @@ pub struct ParsedString {
     pub templated_file: TemplatedFile,
     pub config: FluffConfig,
     pub f_name: String,
+    pub encoding: FileEncoding,
+    /// The line ending to write fixes back with.
+    pub line_ending: LineEnding,
     pub source_str: String,
+    /// What parsing took, when it was asked for.
+    pub parse_statistics: Option<ParseStatistics>,
+}
+
+/// A change to the text of a file, as made by an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The byte range of the text being replaced, in the source with its
+    /// newlines normalised to `\n`.
+    pub range: Range<usize>,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// The single edit turning `old` into `new`, covering just the part
+    /// which differs.
+    pub fn between(old: &str, new: &str) -> Self {
+        let prefix: usize = old
+            .chars()
+            .zip(new.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(char, _)| char.len_utf8())
+            .sum();
+        let suffix: usize = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(char, _)| char.len_utf8())
+            .sum();
+
+        Self {
+            range: prefix..old.len() - suffix,
+            replacement: new[prefix..new.len() - suffix].to_string(),
+        }
+    }
 }
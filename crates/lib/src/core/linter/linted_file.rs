@@ -1,22 +1,48 @@
 use std::ops::Range;
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use itertools::Itertools;
 
+use crate::core::encoding::FileEncoding;
 use crate::core::errors::{SQLBaseError, SQLLintError};
+use crate::core::linter::common::LineEnding;
+use crate::core::parser::context::ParseStatistics;
 use crate::core::parser::segments::base::ErasedSegment;
 use crate::core::parser::segments::fix::FixPatch;
 use crate::core::templaters::base::{RawFileSlice, TemplatedFile};
+use crate::core::timing::RuleTiming;
 
 #[derive(Debug)]
 pub struct LintedFile {
     pub path: String,
     pub tree: ErasedSegment,
     pub templated_file: TemplatedFile,
+    /// The encoding the file was read with, to write fixes back with.
+    pub encoding: FileEncoding,
+    /// The line ending to write fixes back with, as newlines are normalised
+    /// to `\n` before templating.
+    pub line_ending: LineEnding,
     pub violations: Vec<SQLLintError>,
+    /// How long each step (templating, lexing, parsing, linting, fixing)
+    /// took for this file, in seconds.
+    pub time_dict: AHashMap<String, f64>,
+    /// The timing of every rule run on this file, once per loop.
+    pub rule_timings: Vec<RuleTiming>,
+    /// What parsing took, when the linter was asked to collect it.
+    pub parse_statistics: Option<ParseStatistics>,
 }
 
 impl LintedFile {
+    /// The 1-based column in characters of a 1-based line and byte position,
+    /// as violations are reported.
+    pub fn column(&self, line_no: usize, line_pos: usize) -> usize {
+        let source = &self.templated_file.source_str;
+        let line = source.lines().nth(line_no.saturating_sub(1)).unwrap_or_default();
+        let end = line_pos.saturating_sub(1).min(line.len());
+
+        line.get(..end).unwrap_or(line).chars().count() + 1
+    }
+
     #[allow(unused_variables)]
     pub fn get_violations(&self, fixable: Option<bool>) -> Vec<SQLBaseError> {
         self.violations.clone().into_iter().map(Into::into).collect_vec()
@@ -73,11 +99,25 @@ impl LintedFile {
             &self.templated_file.source_str,
         );
 
-        Self::build_up_fixed_source_string(
+        let fixed = Self::build_up_fixed_source_string(
             &slice_buff,
             &filtered_source_patches,
             &self.templated_file.source_str,
-        )
+        );
+
+        self.restore_line_endings(fixed)
+    }
+
+    /// The source of the file as it was read, before any fixes.
+    pub fn source_string(&self) -> String {
+        self.restore_line_endings(self.templated_file.source_str.clone())
+    }
+
+    fn restore_line_endings(&self, source: String) -> String {
+        match self.line_ending {
+            LineEnding::Lf => source,
+            line_ending => source.replace('\n', line_ending.as_str()),
+        }
     }
 
     #[allow(unused_variables)]
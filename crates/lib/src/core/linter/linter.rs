@@ -4,32 +4,50 @@ use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use ahash::{AHashMap, AHashSet};
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 use regex::Regex;
 use uuid::Uuid;
 use walkdir::WalkDir;
 
+use super::cache::LintCache;
 use super::linted_dir::LintedDir;
 use super::runner::RunnerContext;
 use crate::cli::formatters::OutputStreamFormatter;
 use crate::core::config::FluffConfig;
-use crate::core::errors::{SQLFluffUserError, SQLLexError, SQLLintError, SQLParseError, SqlError};
-use crate::core::linter::common::{ParsedString, RenderedFile};
+use crate::core::encoding::FileEncoding;
+use crate::core::errors::{
+    SQLFluffSkipFile, SQLFluffUserError, SQLLexError, SQLLintError, SQLParseError, SqlError,
+};
+use crate::core::linter::common::{LineEnding, ParsedString, RenderedFile, TextEdit};
 use crate::core::linter::linted_file::LintedFile;
 use crate::core::linter::linting_result::LintingResult;
+use crate::core::parser::context::ParseStatistics;
 use crate::core::parser::lexer::{Lexer, StringOrTemplate};
+use crate::core::parser::markers::PositionMarker;
 use crate::core::parser::parser::Parser;
-use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::parser::segments::base::{pos_marker, shift_segment, ErasedSegment};
 use crate::core::parser::segments::fix::AnchorEditInfo;
-use crate::core::rules::base::{ErasedRule, LintFix, RulePack};
+use crate::core::rules::base::{ErasedRule, LintFix, RuleManifest, RulePack, RuleSet};
 use crate::core::templaters::base::{RawTemplater, TemplatedFile, Templater};
-use crate::rules::get_ruleset;
+use crate::core::timing::RuleTiming;
+use crate::dialects::ansi::FileSegment;
+use crate::helpers::ToErasedSegment;
+use crate::rules::{custom, get_ruleset};
 
 pub struct Linter {
     config: FluffConfig,
     pub formatter: Option<OutputStreamFormatter>,
     templater: Box<dyn Templater>,
     _rules: Vec<ErasedRule>,
+    /// Rules registered by other crates, on top of the standard ones.
+    plugin_rules: Vec<RuleManifest>,
+    /// Rules declared in the config, which are built once for it.
+    custom_rules: Vec<ErasedRule>,
+    /// Why rules declared in the config were skipped.
+    custom_rule_warnings: Vec<String>,
+    pub(crate) cache: Option<LintCache>,
+    /// Whether to collect [`ParseStatistics`] for every linted file.
+    parse_statistics: bool,
 }
 
 impl Linter {
@@ -39,17 +57,71 @@ impl Linter {
         templater: Option<Box<dyn Templater>>,
     ) -> Linter {
         let rules = crate::rules::layout::rules();
+        let (custom_rules, custom_rule_warnings) = custom::rules(&config);
         match templater {
-            Some(templater) => Linter { config, formatter, templater, _rules: rules },
+            Some(templater) => Linter {
+                config,
+                formatter,
+                templater,
+                _rules: rules,
+                plugin_rules: Vec::new(),
+                custom_rules,
+                custom_rule_warnings,
+                cache: None,
+                parse_statistics: false,
+            },
             None => Linter {
                 config,
                 formatter,
                 templater: Box::<RawTemplater>::default(),
                 _rules: rules,
+                plugin_rules: Vec::new(),
+                custom_rules,
+                custom_rule_warnings,
+                cache: None,
+                parse_statistics: false,
             },
         }
     }
 
+    /// Cache the results of linting files in `dir`, to skip files which
+    /// haven't changed when linting them again.
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache = LintCache::new(dir, &self.config).with_rules(&self.plugin_rules).into();
+        self
+    }
+
+    /// Collect the [`ParseStatistics`] of every linted file, which is slower.
+    pub fn with_parse_statistics(mut self) -> Self {
+        self.parse_statistics = true;
+        self
+    }
+
+    /// Register rules from outside of sqruff, which are then selected and
+    /// configured like the standard ones.
+    ///
+    /// This is how a crate of in-house rules is plugged into a binary of its
+    /// own:
+    ///
+    /// ```ignore
+    /// let linter = Linter::new(config, None, None).with_rules([
+    ///     RuleManifest::new(RuleAcme01.erased()).aliases(&["ACME_L001"]),
+    ///     RuleManifest::new(RuleAcme02.erased()),
+    /// ]);
+    /// ```
+    ///
+    /// Rules are picked up by their `code`, `name`, `groups` and aliases, so
+    /// they should give themselves a group of their own and be in `all` to
+    /// run by default. Rules whose code is already taken aren't registered.
+    pub fn with_rules(mut self, rules: impl IntoIterator<Item = impl Into<RuleManifest>>) -> Self {
+        let start = self.plugin_rules.len();
+        self.plugin_rules.extend(rules.into_iter().map(Into::into));
+
+        let manifests = &self.plugin_rules[start..];
+        self.cache = self.cache.take().map(|cache| cache.with_rules(manifests));
+        self
+    }
+
     /// Lint strings directly.
     pub fn lint_string_wrapped(
         &mut self,
@@ -108,6 +180,176 @@ impl Linter {
         Ok(Self::parse_rendered(rendered, parse_statistics))
     }
 
+    /// Parse a string again after an edit to it, such as a keystroke in an
+    /// editor.
+    ///
+    /// Only the top-level statements touched by the edit are lexed and parsed
+    /// again. The segments before them are reused as they are, and the ones
+    /// after are moved to their new position. Templated files are parsed
+    /// again in full.
+    ///
+    /// The range of `edit` is in bytes of the previous source with its
+    /// newlines normalised to `\n`, which is what [`TextEdit::between`] gives
+    /// for the normalised texts. Ranges outside of it, or which don't fall on
+    /// character boundaries, are an error.
+    pub fn reparse_string(
+        &self,
+        previous: ParsedString,
+        edit: &TextEdit,
+    ) -> Result<ParsedString, SQLFluffUserError> {
+        let replacement = Self::normalise_newlines(&edit.replacement);
+        let mut in_str = previous.templated_file.source_str.clone();
+        if in_str.get(edit.range.clone()).is_none() {
+            return Err(SQLFluffUserError::new(format!(
+                "The edit range {:?} isn't on character boundaries of the {} bytes of source.",
+                edit.range,
+                in_str.len()
+            )));
+        }
+        in_str.replace_range(edit.range.clone(), &replacement);
+
+        let mut rendered =
+            self.render_string(in_str, previous.f_name.clone(), &previous.config, None)?;
+        rendered.encoding = previous.encoding;
+        rendered.line_ending = previous.line_ending;
+
+        let is_raw = |templated_file: &TemplatedFile| {
+            templated_file.templated_str.as_deref() == Some(templated_file.source_str.as_str())
+        };
+
+        let Some(tree) = previous
+            .tree
+            .filter(|_| is_raw(&previous.templated_file) && is_raw(&rendered.templated_file))
+        else {
+            return Ok(Self::parse_rendered(rendered, false));
+        };
+
+        let t0 = Instant::now();
+        let templated_file = rendered.templated_file.clone();
+        let offset = replacement.len() as isize - edit.range.len() as isize;
+
+        // The top-level segments from the delimiter before the edit up to and
+        // including the one after it, leaving the end of file marker alone.
+        let children = tree.segments();
+        let is_delimiter = |segment: &ErasedSegment| segment.is_type("statement_terminator");
+        let source_slice = |idx: usize| children[idx].get_position_marker().unwrap().source_slice;
+
+        let end_of_file = children
+            .iter()
+            .rposition(|child| child.is_type("end_of_file"))
+            .unwrap_or(children.len());
+        let first = (0..end_of_file)
+            .find(|&idx| source_slice(idx).end >= edit.range.start)
+            .unwrap_or(end_of_file);
+        let last = (0..end_of_file)
+            .rfind(|&idx| source_slice(idx).start <= edit.range.end)
+            .unwrap_or(first)
+            .max(first);
+
+        let start = children[..first].iter().rposition(is_delimiter).map_or(0, |idx| idx + 1);
+        let end = children[last..end_of_file]
+            .iter()
+            .position(is_delimiter)
+            .map_or(end_of_file, |idx| last + idx + 1);
+
+        let region_start = if start < end { source_slice(start).start } else { edit.range.start };
+        let region_end = if start < end { source_slice(end - 1).end } else { edit.range.end };
+        let region_start = region_start.min(edit.range.start);
+        let region_end = (region_end.max(edit.range.end) as isize + offset) as usize;
+
+        let lexer = Lexer::new(&rendered.config, None);
+        let Ok((tokens, _)) = lexer.lex(StringOrTemplate::String(
+            templated_file.source_str[region_start..region_end].to_string(),
+        )) else {
+            return Ok(Self::parse_rendered(rendered, false));
+        };
+
+        let tokens = tokens
+            .iter()
+            .filter(|token| !token.is_type("end_of_file"))
+            .map(|token| shift_segment(token, region_start as isize, &templated_file))
+            .collect_vec();
+
+        let t1 = Instant::now();
+
+        let (content, content_violations) = if tokens.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            match Parser::new(&rendered.config, None).parse(
+                &tokens,
+                Some(rendered.f_name.clone()),
+                false,
+            ) {
+                Ok((Some(parsed), violations)) => (parsed.segments().to_vec(), violations),
+                Ok((None, violations)) => (Vec::new(), violations),
+                Err(error) => (Vec::new(), vec![error]),
+            }
+        };
+
+        let before = children[..start].to_vec();
+        let mut after = children[end..end_of_file]
+            .iter()
+            .map(|child| shift_segment(child, offset, &templated_file))
+            .collect_vec();
+
+        // Like the lexer, the end of file marker shares the position of the
+        // last lexed segment.
+        if let Some(end_of_file) = children.get(end_of_file) {
+            let position_marker = chain!(&before, &content, &after)
+                .rev()
+                .find_map(|segment| {
+                    let raw_segments = segment.get_raw_segments();
+                    raw_segments.iter().rev().find(|raw| !raw.is_meta())?.get_position_marker()
+                })
+                .map(|marker| PositionMarker { templated_file: templated_file.clone(), ..marker })
+                .unwrap_or_else(|| {
+                    PositionMarker::from_point(0, 0, templated_file.clone(), None, None)
+                });
+
+            let mut end_of_file = end_of_file.clone_box();
+            end_of_file.get_mut().set_position_marker(position_marker.into());
+            after.push(end_of_file);
+        }
+
+        // The violations of the segments we kept are found again from their
+        // unparsable sections.
+        let unparsable_violations = |segments: &[ErasedSegment]| {
+            segments
+                .iter()
+                .flat_map(|segment| Parser::unparsable_violations(segment, Vec::new()))
+                .collect_vec()
+        };
+        let violations = chain!(
+            unparsable_violations(&before),
+            content_violations,
+            unparsable_violations(&after)
+        )
+        .map(Into::into)
+        .collect();
+
+        let mut root = tree.new(chain!(before, content, after).collect());
+        let mut position_marker = pos_marker(&*root);
+        position_marker.templated_file = templated_file;
+        root.get_mut().set_position_marker(position_marker.into());
+
+        let mut time_dict = rendered.time_dict;
+        time_dict.insert("lexing".to_string(), (t1 - t0).as_secs_f64());
+        time_dict.insert("parsing".to_string(), t1.elapsed().as_secs_f64());
+
+        Ok(ParsedString {
+            tree: root.into(),
+            violations,
+            time_dict,
+            templated_file: rendered.templated_file,
+            config: rendered.config,
+            f_name: rendered.f_name,
+            encoding: rendered.encoding,
+            line_ending: rendered.line_ending,
+            source_str: rendered.source_str,
+            parse_statistics: None,
+        })
+    }
+
     /// Lint a string.
     #[allow(clippy::too_many_arguments)]
     pub fn lint_string(
@@ -161,17 +403,118 @@ impl Linter {
             result.paths[path].add(linted_file);
         }
 
+        result.stop_timer();
         result
     }
 
+    /// The standard rules, along with any registered with
+    /// [`Linter::with_rules`] and custom rules declared in config.
+    pub fn get_ruleset(&self) -> RuleSet {
+        let mut rs = get_ruleset();
+        for rule in &self.plugin_rules {
+            rs.register_rule(rule.clone());
+        }
+        for rule in &self.custom_rules {
+            rs.register_rule(rule.clone());
+        }
+        rs
+    }
+
+    /// The rules selected by the config, along with warnings about the config
+    /// to tell the user about.
     pub fn get_rulepack(&self) -> RulePack {
-        let rs = get_ruleset();
-        rs.get_rulepack(&self.config)
+        let rs = self.get_ruleset();
+        let mut rule_pack = rs.get_rulepack(&self.config);
+        rule_pack.warnings.splice(0..0, self.custom_rule_warnings.iter().cloned());
+        rule_pack
+    }
+
+    pub fn render_file(&mut self, fname: String) -> Result<RenderedFile, SQLFluffSkipFile> {
+        // Implement byte limit (before templating).
+        let byte_limit = self.config.get("large_file_skip_byte_limit", "core").as_int();
+        if let Some(limit) = byte_limit.filter(|&limit| limit > 0) {
+            let size =
+                std::fs::metadata(&fname).map_err(|error| Self::unreadable(&fname, error))?.len();
+            if size > limit as u64 {
+                return Err(SQLFluffSkipFile::new(format!(
+                    "Length of file {fname:?} is {size} bytes which is over the limit of {limit} \
+                     bytes. Skipping to avoid parser lock. Users can increase this limit in their \
+                     config by setting the 'large_file_skip_byte_limit' value, or disable by \
+                     setting it to zero."
+                )));
+            }
+        }
+
+        let bytes = std::fs::read(&fname).map_err(|error| Self::unreadable(&fname, error))?;
+        let encoding = self.encoding(&bytes);
+        let in_str = encoding.decode(&bytes).ok_or_else(|| {
+            SQLFluffSkipFile::new(format!(
+                "Unable to decode file {fname:?} as {}. Skipping, as fixing it would lose the \
+                 undecodable bytes. Set the 'encoding' config value to the one it's written in.",
+                encoding.name()
+            ))
+        })?;
+
+        // Implement char limit (before templating).
+        let char_limit = self.config.get("large_file_skip_char_limit", "core").as_int();
+        if let Some(limit) = char_limit.filter(|&limit| limit > 0)
+            && in_str.chars().count() > limit as usize
+        {
+            return Err(SQLFluffSkipFile::new(format!(
+                "Length of file {fname:?} is over {limit} characters. Skipping to avoid parser \
+                 lock. Users can increase this limit in their config by setting the \
+                 'large_file_skip_char_limit' value, or disable by setting it to zero."
+            )));
+        }
+
+        let mut rendered = self.render_string(in_str, fname, &self.config, None).unwrap();
+        rendered.encoding = encoding;
+        Ok(rendered)
+    }
+
+    /// Files which can't be read, such as ones deleted since they were
+    /// found, are skipped like those over the size limits.
+    fn unreadable(fname: &str, error: std::io::Error) -> SQLFluffSkipFile {
+        SQLFluffSkipFile::new(format!("Unable to read file {fname:?}: {error}. Skipping."))
     }
 
-    pub fn render_file(&mut self, fname: String) -> RenderedFile {
-        let in_str = std::fs::read_to_string(&fname).unwrap();
-        self.render_string(in_str, fname, &self.config, None).unwrap()
+    /// The encoding of the contents of a file, from the config.
+    fn encoding(&self, bytes: &[u8]) -> FileEncoding {
+        let config_encoding =
+            self.config.get("encoding", "core").as_string().unwrap_or("autodetect");
+        FileEncoding::detect(bytes, config_encoding)
+    }
+
+    /// A linted file for `violations` of `fname` found in the cache.
+    ///
+    /// The file isn't parsed, so it has an empty tree and can't be fixed.
+    pub(crate) fn lint_cached(
+        &mut self,
+        fname: String,
+        source: &[u8],
+        violations: Vec<SQLLintError>,
+    ) -> LintedFile {
+        // Files which can't be decoded are skipped, so are never cached.
+        let encoding = self.encoding(source);
+        let in_str = encoding.decode(source).unwrap_or_default();
+
+        let linted_file = LintedFile {
+            path: fname,
+            tree: FileSegment::default().to_erased_segment(),
+            line_ending: LineEnding::detect(&in_str),
+            templated_file: TemplatedFile::from_string(Self::normalise_newlines(&in_str)),
+            encoding,
+            violations,
+            time_dict: AHashMap::new(),
+            rule_timings: Vec::new(),
+            parse_statistics: None,
+        };
+
+        if let Some(formatter) = &mut self.formatter {
+            formatter.dispatch_file_violations(&linted_file, false, false);
+        }
+
+        linted_file
     }
 
     pub fn lint_rendered(
@@ -180,7 +523,7 @@ impl Linter {
         rule_pack: &RulePack,
         fix: bool,
     ) -> LintedFile {
-        let parsed = Self::parse_rendered(rendered, false);
+        let parsed = Self::parse_rendered(rendered, self.parse_statistics);
         self.lint_parsed(parsed, rule_pack.rules.clone(), fix)
     }
 
@@ -190,20 +533,62 @@ impl Linter {
         rules: Vec<ErasedRule>,
         fix: bool,
     ) -> LintedFile {
-        let violations = parsed_string.violations;
-        assert!(violations.is_empty());
-
-        let (tree, initial_linting_errors) = if let Some(tree) = parsed_string.tree {
-            self.lint_fix_parsed(tree, rules, fix)
+        // Parse errors are reported alongside the linting errors, and rules
+        // still run over the parts of the file which did parse.
+        let mut violations: Vec<SQLLintError> =
+            parsed_string.violations.into_iter().map(Into::into).collect();
+
+        // Fixing around unparsable sections can corrupt the file, so it has to
+        // be asked for.
+        let fix_even_unparsable =
+            self.config.get("fix_even_unparsable", "core").as_bool().unwrap_or_default();
+        let has_unparsable = parsed_string.tree.as_ref().is_some_and(|tree| {
+            !tree.recursive_crawl(&["unparsable"], false, None, true).is_empty()
+        });
+        let fix = if fix && !fix_even_unparsable && has_unparsable {
+            self.warn(&format!(
+                "Fixes for {} not applied, as it has parsing errors. Set fix_even_unparsable to \
+                 apply them anyway.",
+                parsed_string.f_name
+            ));
+            false
         } else {
-            unimplemented!()
+            fix
         };
 
+        let t0 = Instant::now();
+        let (tree, initial_linting_errors, rule_timings, fixing_time) =
+            if let Some(tree) = parsed_string.tree {
+                self.lint_fix_parsed(
+                    tree,
+                    &parsed_string.templated_file,
+                    &parsed_string.f_name,
+                    rules,
+                    fix,
+                )
+            } else {
+                unimplemented!()
+            };
+
+        violations.extend(initial_linting_errors);
+        self.apply_severity(&mut violations);
+
+        let mut time_dict = parsed_string.time_dict;
+        time_dict.insert("linting".to_string(), t0.elapsed().as_secs_f64() - fixing_time);
+        if fix {
+            time_dict.insert("fixing".to_string(), fixing_time);
+        }
+
         let linted_file = LintedFile {
             path: parsed_string.f_name,
             tree,
             templated_file: parsed_string.templated_file,
-            violations: initial_linting_errors,
+            encoding: parsed_string.encoding,
+            line_ending: parsed_string.line_ending,
+            violations,
+            time_dict,
+            rule_timings,
+            parse_statistics: parsed_string.parse_statistics,
         };
 
         if let Some(formatter) = &mut self.formatter {
@@ -213,14 +598,72 @@ impl Linter {
         linted_file
     }
 
+    /// Tell the user about `message` through the formatter, if there is one.
+    pub(crate) fn warn(&mut self, message: &str) {
+        match &mut self.formatter {
+            Some(formatter) => formatter.dispatch_warning(message),
+            None => tracing::warn!("{message}"),
+        }
+    }
+
+    /// Drop the violations in the categories the `ignore` config key lists,
+    /// and downgrade the violations of rules listed in `warnings`, or whose
+    /// `severity` is configured as `warning`, to warnings.
+    fn apply_severity(&self, violations: &mut Vec<SQLLintError>) {
+        let list = |key| {
+            self.config
+                .get(key, "core")
+                .as_array()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|value| value.as_string())
+                .collect_vec()
+        };
+        let ignore = list("ignore");
+        let warnings = list("warnings");
+        let rules = self.config.get_section("rules");
+
+        violations.retain(|violation| {
+            let category = match violation.rule_code() {
+                "LXR" => "lexing",
+                "PRS" => "parsing",
+                "TMP" => "templating",
+                _ => "linting",
+            };
+
+            !ignore.contains(&category)
+        });
+
+        for violation in violations {
+            let severity = violation
+                .rule
+                .as_ref()
+                .and_then(|rule| rules.get(rule.config_ref()))
+                .and_then(|section| section.as_map())
+                .and_then(|section| section.get("severity"))
+                .and_then(|severity| severity.as_string());
+
+            violation.warning =
+                warnings.contains(&violation.rule_code()) || severity == Some("warning");
+        }
+    }
+
+    /// Lint, and optionally fix, a parsed tree of the file at `path`.
+    ///
+    /// Besides the fixed tree and the initial violations, this returns the
+    /// timing of every rule run and the time spent applying fixes.
     #[allow(unused_variables)]
     pub fn lint_fix_parsed(
         &self,
         mut tree: ErasedSegment,
+        templated_file: &TemplatedFile,
+        path: &str,
         rules: Vec<ErasedRule>,
         fix: bool,
-    ) -> (ErasedSegment, Vec<SQLLintError>) {
+    ) -> (ErasedSegment, Vec<SQLLintError>, Vec<RuleTiming>, f64) {
         let mut tmp;
+        let mut rule_timings = Vec::new();
+        let mut fixing_time = 0.0;
 
         let mut initial_linting_errors = Vec::new();
         let phases: &[_] = if fix { &["main", "post"] } else { &["main"] };
@@ -260,8 +703,21 @@ impl Linter {
                         continue;
                     }
 
-                    let (linting_errors, fixes) =
-                        rule.crawl(&self.config.dialect, fix, tree.clone(), &self.config);
+                    let t0 = Instant::now();
+                    let (linting_errors, fixes, crawled_segments) = rule.crawl(
+                        &self.config.dialect,
+                        fix,
+                        tree.clone(),
+                        templated_file,
+                        path,
+                        &self.config,
+                    );
+                    rule_timings.push(RuleTiming {
+                        code: rule.code().to_string(),
+                        name: rule.name().to_string(),
+                        time: t0.elapsed().as_secs_f64(),
+                        crawled_segments,
+                    });
                     let anchor_info = compute_anchor_edit_info(fixes.clone());
 
                     if is_first_linter_pass {
@@ -275,8 +731,10 @@ impl Linter {
                         // This is the happy path. We have fixes, now we want to apply them.
                         let _last_fixes = fixes;
 
+                        let t0 = Instant::now();
                         let (new_tree, _, _, valid) =
                             tree.apply_fixes(&self.config.dialect, anchor_info);
+                        fixing_time += t0.elapsed().as_secs_f64();
 
                         if false {
                             println!(
@@ -297,7 +755,32 @@ impl Linter {
             }
         }
 
-        (tree, initial_linting_errors)
+        (tree, initial_linting_errors, rule_timings, fixing_time)
+    }
+
+    /// The source of `file` with just the fixes of `violation` applied, or
+    /// `None` if it can't be fixed.
+    pub fn fix_violation(&self, file: &LintedFile, violation: &SQLLintError) -> Option<String> {
+        if violation.fixes.is_empty() {
+            return None;
+        }
+
+        let anchor_info = compute_anchor_edit_info(violation.fixes.clone());
+        let (tree, _, _, _) = file.tree.apply_fixes(&self.config.dialect, anchor_info);
+
+        let fixed = LintedFile {
+            path: file.path.clone(),
+            tree,
+            templated_file: file.templated_file.clone(),
+            encoding: file.encoding,
+            line_ending: file.line_ending,
+            violations: Vec::new(),
+            time_dict: AHashMap::new(),
+            rule_timings: Vec::new(),
+            parse_statistics: None,
+        };
+
+        Some(fixed.fix_string())
     }
 
     /// Template the file.
@@ -313,11 +796,24 @@ impl Linter {
         // linter_logger.info!("TEMPLATING RAW [{}] ({})", self.templater.name, f_name);
 
         // Start the templating timer
-        let _t0 = Instant::now();
+        let t0 = Instant::now();
+
+        let encoding = match encoding {
+            Some(label) => FileEncoding::for_label(&label)
+                .ok_or_else(|| SQLFluffUserError::new(format!("Unknown encoding {label:?}")))?,
+            None => FileEncoding::default(),
+        };
 
         // Newlines are normalised to unix-style line endings (\n).
         // The motivation is that Jinja normalises newlines during templating and
         // we want consistent mapping between the raw and templated slices.
+        // The original line ending is restored when fixing, unless the config
+        // asks for a particular one.
+        let line_ending = match config.get("line_ending", "core").as_string() {
+            Some(value) => LineEnding::from_config(value)?,
+            None => None,
+        }
+        .unwrap_or_else(|| LineEnding::detect(&in_str));
         let in_str = Self::normalise_newlines(in_str.as_str());
 
         // Since Linter.__init__() does not require a dialect to be specified,
@@ -367,20 +863,18 @@ impl Linter {
             // );
         };
 
-        // // Record time
-        // TODO Implement time
-        // let time_dict = [("templating", t0.elapsed().as_secs_f64())]
-        //     .iter()
-        //     .cloned()
-        //     .collect();
+        // Record time
+        let time_dict =
+            [("templating".to_string(), t0.elapsed().as_secs_f64())].into_iter().collect();
 
         Ok(RenderedFile {
             templated_file: templated_file.unwrap(),
             templater_violations,
             config: config.clone(),
-            time_dict: AHashMap::new(),
+            time_dict,
             f_name: f_name.to_owned(),
-            encoding: encoding.to_owned().unwrap_or_else(|| "UTF-8".into()),
+            encoding,
+            line_ending,
             source_str: f_name.to_owned(),
         })
     }
@@ -417,14 +911,16 @@ impl Linter {
         // linter_logger.info("PARSING ({})", rendered.fname);
 
         let parsed: Option<ErasedSegment>;
+        let mut statistics = None;
         if let Some(token_list) = tokens {
-            let (p, pvs) = Self::parse_tokens(
+            let (p, pvs, s) = Self::parse_tokens(
                 &token_list,
                 &rendered.config,
                 Some(rendered.f_name.to_string()),
                 parse_statistics,
             );
             parsed = p;
+            statistics = s;
             violations.extend(pvs.into_iter().map(Into::into));
         } else {
             parsed = None;
@@ -443,7 +939,10 @@ impl Linter {
             templated_file: rendered.templated_file,
             config: rendered.config,
             f_name: rendered.f_name,
+            encoding: rendered.encoding,
+            line_ending: rendered.line_ending,
             source_str: rendered.source_str,
+            parse_statistics: statistics,
         }
     }
 
@@ -452,19 +951,22 @@ impl Linter {
         config: &FluffConfig,
         f_name: Option<String>,
         parse_statistics: bool,
-    ) -> (Option<ErasedSegment>, Vec<SQLParseError>) {
+    ) -> (Option<ErasedSegment>, Vec<SQLParseError>, Option<ParseStatistics>) {
         let mut parser = Parser::new(config, None);
         let mut violations: Vec<SQLParseError> = Vec::new();
 
         let parsed = match parser.parse(tokens, f_name, parse_statistics) {
-            Ok(parsed) => parsed,
+            Ok((parsed, parse_violations)) => {
+                violations.extend(parse_violations);
+                parsed
+            }
             Err(error) => {
                 violations.push(error);
                 None
             }
         };
 
-        (parsed, violations)
+        (parsed, violations, parser.statistics().cloned())
     }
 
     /// Lex a templated file.
@@ -639,8 +1141,19 @@ fn compute_anchor_edit_info(fixes: Vec<LintFix>) -> AHashMap<Uuid, AnchorEditInf
 
 #[cfg(test)]
 mod tests {
-    use crate::core::config::FluffConfig;
+    use ahash::AHashMap;
+    use itertools::Itertools;
+
+    use crate::api::simple::fix;
+    use crate::core::config::{ConfigLoader, FluffConfig, Value};
+    use crate::core::linter::common::{LineEnding, TextEdit};
     use crate::core::linter::linter::Linter;
+    use crate::core::parser::segments::base::ErasedSegment;
+    use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleManifest};
+    use crate::core::rules::context::RuleContext;
+    use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+    use crate::core::templaters::base::{RawFileSlice, TemplatedFile, TemplatedFileSlice};
+    use crate::rules::layout::LT01::RuleLT01;
 
     fn normalise_paths(paths: Vec<String>) -> Vec<String> {
         paths.into_iter().map(|path| path.replace(['/', '\\'], ".")).collect()
@@ -710,7 +1223,86 @@ mod tests {
         assert_eq!(normalise_paths(paths), &["test.fixtures.linter.indentation_errors.sql"]);
     }
 
-    // test__linter__skip_large_bytes
+    #[test]
+    fn test__linter__skip_large_bytes() {
+        for (filesize, raises_skip) in [(0, false), (5, true), (2000, false)] {
+            let config = FluffConfig::new(
+                [(
+                    "core".into(),
+                    Value::Map(
+                        [("large_file_skip_byte_limit".into(), Value::Int(filesize))].into(),
+                    ),
+                )]
+                .into(),
+                None,
+                None,
+            );
+            let mut linter = Linter::new(config, None, None);
+
+            let result = linter.render_file("test/fixtures/linter/indentation_errors.sql".into());
+            match result {
+                Err(skip) => {
+                    assert!(raises_skip);
+                    assert!(skip.to_string().contains("Skipping"));
+                    assert!(skip.to_string().contains(&format!("over the limit of {filesize}")));
+                }
+                Ok(_) => assert!(!raises_skip),
+            }
+        }
+    }
+
+    #[test]
+    fn test__linter__skip_large_chars() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("large_file_skip_char_limit".into(), Value::Int(5))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        );
+        let mut linter = Linter::new(config, None, None);
+
+        let skip =
+            linter.render_file("test/fixtures/linter/indentation_errors.sql".into()).unwrap_err();
+        assert!(skip.to_string().contains("is over 5 characters"));
+    }
+
+    #[test]
+    fn test__linter__skip_unreadable() {
+        let mut linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+
+        let skip = linter.render_file("test/fixtures/linter/missing.sql".into()).unwrap_err();
+        assert!(skip.to_string().starts_with("Unable to read file"));
+    }
+
+    #[test]
+    fn test__linter__skip_undecodable() {
+        let dir = tempdir::TempDir::new("sqruff_encoding").unwrap();
+        let path = dir.path().join("latin1.sql");
+        std::fs::write(&path, b"SELECT '\xE9'  AS a\n").unwrap();
+
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("encoding".into(), Value::String("utf-8".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        );
+        let mut linter = Linter::new(config, None, None);
+
+        let fname = path.to_string_lossy().into_owned();
+        let skip = linter.render_file(fname).unwrap_err();
+        assert!(skip.to_string().starts_with("Unable to decode file"));
+
+        // It isn't among the files to write fixes back to either.
+        let result = linter.lint_paths(vec![path], true);
+        assert!(result.paths.iter().all(|path| path.files.is_empty()));
+    }
+
     // test__linter__path_from_paths__not_exist
     // test__linter__path_from_paths__not_exist_ignore
     // test__linter__path_from_paths__explicit_ignore
@@ -735,8 +1327,372 @@ mod tests {
         assert!(parsed.violations.is_empty());
     }
 
+    #[test]
+    fn test__linter__lint_around_unparsable() {
+        let mut linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let result = linter.lint_string_wrapped(
+            "SELECT 1;\nNOT VALID SQL;\nSELECT  2;\n".into(),
+            None,
+            None,
+            vec![RuleLT01::default().erased()],
+        );
+
+        let codes = result.paths[0].files[0]
+            .violations
+            .iter()
+            .map(|violation| (violation.rule_code(), violation.line_no))
+            .collect::<Vec<_>>();
+        assert_eq!(codes, [("PRS", 2), ("LT01", 3)]);
+    }
+
+    #[test]
+    fn test__linter__violation_ranges() {
+        let mut linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let rules = linter.get_rulepack().rules;
+        let result =
+            linter.lint_string_wrapped("SELECT a  ,b\nFROM t\nWHERE )\n".into(), None, None, rules);
+
+        let ranges = result.paths[0].files[0]
+            .violations
+            .iter()
+            .map(|violation| {
+                (
+                    violation.rule_code().to_string(),
+                    (violation.line_no, violation.line_pos),
+                    (violation.end_line_no, violation.end_line_pos),
+                    violation.source_slice.clone(),
+                )
+            })
+            .collect_vec();
+
+        assert_eq!(
+            ranges,
+            [
+                ("PRS".into(), (3, 7), (3, 8), 26..27),
+                ("LT01".into(), (1, 9), (1, 11), 8..10),
+                ("LT01".into(), (1, 12), (1, 13), 11..12),
+                ("LT01".into(), (3, 6), (3, 7), 25..26),
+                ("LT09".into(), (1, 1), (1, 13), 0..12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test__linter__severity() {
+        let dir = tempdir::TempDir::new("sqruff_severity").unwrap();
+        let config = "[sqlfluff]
+warnings = LT01
+ignore = parsing
+
+[sqlfluff:rules:layout.select_targets]
+severity = warning
+";
+        std::fs::write(dir.path().join(".sqlfluff"), config).unwrap();
+        let config = FluffConfig::new(ConfigLoader.load_config_at_path(dir.path()), None, None);
+
+        let mut linter = Linter::new(config, None, None);
+        let rules = linter.get_rulepack().rules;
+        let result =
+            linter.lint_string_wrapped("select a  ,b\nFROM t\nWHERE )\n".into(), None, None, rules);
+
+        let violations = result.paths[0].files[0]
+            .violations
+            .iter()
+            .map(|violation| (violation.rule_code(), violation.warning))
+            .collect_vec();
+
+        assert_eq!(
+            violations,
+            [("CP01", false), ("LT01", true), ("LT01", true), ("LT01", true), ("LT09", true)]
+        );
+    }
+
+    #[test]
+    fn test__linter__ignore_templated_areas() {
+        // `{{x}}` renders as `a  ,b`, which is the same length so the source
+        // and templated positions line up.
+        let source = "SELECT {{x}} FROM  t\n";
+        let rendered = "SELECT a  ,b FROM  t\n";
+        let templated_file = TemplatedFile::new(
+            source.into(),
+            "<string>".into(),
+            Some(rendered.into()),
+            Some(vec![
+                TemplatedFileSlice::new("literal", 0..7, 0..7),
+                TemplatedFileSlice::new("templated", 7..12, 7..12),
+                TemplatedFileSlice::new("literal", 12..21, 12..21),
+            ]),
+            Some(vec![
+                RawFileSlice::new("SELECT ".into(), "literal".into(), 0, None, None),
+                RawFileSlice::new("{{x}}".into(), "templated".into(), 7, None, None),
+                RawFileSlice::new(" FROM  t\n".into(), "literal".into(), 12, None, None),
+            ]),
+        )
+        .unwrap();
+
+        for (ignore_templated_areas, expected) in
+            [(true, vec!["LT01"]), (false, vec!["LT01", "LT01", "LT01", "LT09"])]
+        {
+            let config = FluffConfig::new(
+                [(
+                    "core".into(),
+                    Value::Map(
+                        [("ignore_templated_areas".into(), Value::Bool(ignore_templated_areas))]
+                            .into(),
+                    ),
+                )]
+                .into(),
+                None,
+                None,
+            );
+            let linter = Linter::new(config, None, None);
+            let tree = linter.parse_string(rendered.into(), None, None, None).unwrap().tree;
+
+            let (_, violations, _, _) = linter.lint_fix_parsed(
+                tree.unwrap(),
+                &templated_file,
+                "test.sql",
+                linter.get_rulepack().rules,
+                false,
+            );
+            let codes =
+                violations.iter().map(|violation| violation.rule_code()).sorted().collect_vec();
+
+            assert_eq!(codes, expected, "ignore_templated_areas = {ignore_templated_areas}");
+        }
+    }
+
+    #[test]
+    fn test__linter__fix_violation() {
+        let mut linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let rules = linter.get_rulepack().rules;
+        let result = linter.lint_string_wrapped("SELECT a  ,b FROM t\n".into(), None, None, rules);
+        let file = &result.paths[0].files[0];
+
+        let fixes = file
+            .violations
+            .iter()
+            .filter(|violation| violation.rule_code() == "LT01")
+            .map(|violation| linter.fix_violation(file, violation).unwrap())
+            .collect_vec();
+        assert_eq!(fixes, ["SELECT a,b FROM t\n", "SELECT a  , b FROM t\n"]);
+    }
+
+    #[test]
+    fn test__linter__lint_paths_cached() {
+        let cache_dir = tempdir::TempDir::new("sqruff_cache").unwrap();
+        let path = "test/fixtures/linter/indentation_errors.sql";
+
+        let mut linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None)
+            .with_cache(cache_dir.path());
+        let linted = linter.lint_paths(vec![path.into()], false);
+        let cached = linter.lint_paths(vec![path.into()], false);
+
+        let (linted, cached) = (&linted.paths[0].files[0], &cached.paths[0].files[0]);
+        assert!(!linted.violations.is_empty());
+        assert_eq!(linted.violations, cached.violations);
+        assert!(linted.time_dict.contains_key("parsing"));
+        assert!(!cached.time_dict.contains_key("parsing"));
+    }
+
+    #[test]
+    fn test__linter__fix_even_unparsable() {
+        let sql = "SELECT a  , b FROM t;\nNOT VALID SQL;\n";
+
+        for (fix_even_unparsable, expected) in
+            [(false, sql), (true, "SELECT a, b FROM t;\nNOT VALID SQL;\n")]
+        {
+            let config = FluffConfig::new(
+                [(
+                    "core".into(),
+                    Value::Map(
+                        [("fix_even_unparsable".into(), Value::Bool(fix_even_unparsable))].into(),
+                    ),
+                )]
+                .into(),
+                None,
+                None,
+            );
+            let mut linter = Linter::new(config, None, None);
+            let result = linter.lint_string_wrapped(
+                sql.into(),
+                None,
+                Some(true),
+                vec![RuleLT01::default().erased()],
+            );
+
+            assert_eq!(result.paths[0].files[0].fix_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test__linter__lint_paths_parse_statistics() {
+        let path = "test/fixtures/linter/passing.sql";
+
+        let mut linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let result = linter.lint_paths(vec![path.into()], false);
+        assert!(result.paths[0].files[0].parse_statistics.is_none());
+
+        let mut linter = linter.with_parse_statistics();
+        let result = linter.lint_paths(vec![path.into()], false);
+        let statistics = result.paths[0].files[0].parse_statistics.as_ref().unwrap();
+        assert!(statistics.match_attempts > 0);
+    }
+
+    #[test]
+    fn test__linter__with_rules() {
+        #[derive(Debug, Clone)]
+        struct RuleAcme01;
+
+        impl Rule for RuleAcme01 {
+            fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+                self.clone().erased()
+            }
+
+            fn name(&self) -> &'static str {
+                "acme.no_wildcards"
+            }
+
+            fn description(&self) -> &'static str {
+                "Columns must be listed."
+            }
+
+            fn groups(&self) -> &'static [&'static str] {
+                &["all", "acme"]
+            }
+
+            fn code(&self) -> &'static str {
+                "ACME01"
+            }
+
+            fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+                vec![LintResult::new(context.segment.into(), Vec::new(), None, None, None)]
+            }
+
+            fn crawl_behaviour(&self) -> Crawler {
+                SegmentSeekerCrawler::new(["wildcard_expression"].into()).into()
+            }
+        }
+
+        let codes = |rules: &str| {
+            let config = FluffConfig::new(
+                [(
+                    "core".into(),
+                    Value::Map([("rules".into(), Value::String(rules.into()))].into()),
+                )]
+                .into(),
+                None,
+                None,
+            );
+            let mut linter = Linter::new(config, None, None)
+                .with_rules([RuleManifest::new(RuleAcme01.erased()).aliases(&["ACME_L001"])]);
+            let rules = linter.get_rulepack().rules();
+            let result = linter.lint_string_wrapped("SELECT * FROM t\n".into(), None, None, rules);
+
+            result.paths[0].files[0]
+                .violations
+                .iter()
+                .map(|it| it.rule_code().to_owned())
+                .collect_vec()
+        };
+
+        for rules in ["ACME01", "acme", "acme.no_wildcards", "ACME_L001"] {
+            assert_eq!(codes(rules), ["ACME01"], "{rules}");
+        }
+        assert!(codes("all").contains(&"ACME01".into()));
+        assert!(codes("LT01").is_empty());
+    }
+
+    #[test]
+    fn test__linter__reparse_string() {
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let sql = "SELECT 1;\nSELECT a FROM b;\nSELECT 3;\n";
+
+        for (range, replacement, expected) in [
+            (17..18, "a, c", "SELECT 1;\nSELECT a, c FROM b;\nSELECT 3;\n"),
+            (8..9, "", "SELECT 1\nSELECT a FROM b;\nSELECT 3;\n"),
+            (26..26, "\nSELECT 2;", "SELECT 1;\nSELECT a FROM b;\nSELECT 2;\nSELECT 3;\n"),
+            (10..26, "NOT VALID SQL;", "SELECT 1;\nNOT VALID SQL;\nSELECT 3;\n"),
+            (37..37, "SELECT 4", "SELECT 1;\nSELECT a FROM b;\nSELECT 3;\nSELECT 4"),
+            (0..37, "", ""),
+        ] {
+            let previous = linter.parse_string(sql.into(), None, None, None).unwrap();
+            let first_statement = previous.tree.as_ref().unwrap().segments()[0].get_uuid();
+
+            let edit = TextEdit { range, replacement: replacement.into() };
+            let reparsed = linter.reparse_string(previous, &edit).unwrap();
+            let parsed = linter.parse_string(expected.into(), None, None, None).unwrap();
+
+            let (reparsed_tree, parsed_tree) =
+                (reparsed.tree.as_ref().unwrap(), parsed.tree.as_ref().unwrap());
+            assert_eq!(reparsed_tree.get_raw().unwrap(), expected);
+            assert_eq!(
+                serde_yaml::to_string(&reparsed_tree.to_serialised(false, true, true)).unwrap(),
+                serde_yaml::to_string(&parsed_tree.to_serialised(false, true, true)).unwrap()
+            );
+
+            let positions = |tree: &ErasedSegment| {
+                tree.get_raw_segments()
+                    .into_iter()
+                    .map(|segment| {
+                        let marker = segment.get_position_marker().unwrap();
+                        (marker.source_slice, marker.working_line_no, marker.working_line_pos)
+                    })
+                    .collect_vec()
+            };
+            assert_eq!(positions(reparsed_tree), positions(parsed_tree));
+            assert_eq!(reparsed.violations.len(), parsed.violations.len());
+
+            // Statements before the edit are reused as they are.
+            if edit.range.start > 10 {
+                assert_eq!(reparsed_tree.segments()[0].get_uuid(), first_statement);
+            }
+        }
+    }
+
+    #[test]
+    fn test__linter__reparse_string_invalid_range() {
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let sql = "SELECT 'é';
+";
+
+        #[allow(clippy::reversed_empty_ranges)]
+        for range in [0..14, 14..14, 9..10, 5..4] {
+            let previous = linter.parse_string(sql.into(), None, None, None).unwrap();
+            let edit = TextEdit { range, replacement: "x".into() };
+            assert!(linter.reparse_string(previous, &edit).is_err(), "{:?}", edit.range);
+        }
+    }
+
     // test__linter__mask_templated_violations
-    // test__linter__encoding
+    #[test]
+    fn test__linter__encoding() {
+        for (fname, config_encoding, has_bom) in [
+            ("test/fixtures/linter/encoding-utf-8.sql", "autodetect", false),
+            ("test/fixtures/linter/encoding-utf-8-sig.sql", "autodetect", true),
+            ("test/fixtures/linter/encoding-utf-8.sql", "utf-8", false),
+            ("test/fixtures/linter/encoding-utf-8-sig.sql", "utf-8", true),
+            ("test/fixtures/linter/encoding-utf-8.sql", "utf-8-sig", true),
+            ("test/fixtures/linter/encoding-utf-8-sig.sql", "utf-8-sig", true),
+            ("test/fixtures/linter/encoding-latin-1.sql", "autodetect", false),
+            ("test/fixtures/linter/encoding-latin-1.sql", "latin-1", false),
+        ] {
+            let config = FluffConfig::new(
+                [(
+                    "core".into(),
+                    Value::Map([("encoding".into(), Value::String(config_encoding.into()))].into()),
+                )]
+                .into(),
+                None,
+                None,
+            );
+            let mut linter = Linter::new(config, None, None);
+
+            let rendered = linter.render_file(fname.into()).unwrap();
+            assert_eq!(rendered.templated_file.source_str, "SELECT 'é' AS a\n");
+            assert_eq!(rendered.encoding.has_bom(), has_bom);
+        }
+    }
     // test_delayed_exception
     // test__attempt_to_change_templater_warning
 
@@ -766,4 +1722,67 @@ mod tests {
 
         assert_eq!(Linter::normalise_newlines(in_str), out_str);
     }
+
+    #[test]
+    fn test_fix_preserves_line_endings() {
+        let rules = vec![RuleLT01::default().erased()];
+
+        assert_eq!(
+            fix("SELECT a ,b\r\nFROM c\r\n".into(), rules.clone()),
+            "SELECT a, b\r\nFROM c\r\n"
+        );
+        assert_eq!(fix("SELECT a ,b\rFROM c\r".into(), rules.clone()), "SELECT a, b\rFROM c\r");
+        assert_eq!(fix("SELECT a ,b\nFROM c\n".into(), rules), "SELECT a, b\nFROM c\n");
+    }
+
+    #[test]
+    fn test_fix_enforces_line_ending() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("line_ending".into(), Value::String("lf".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        );
+        let mut linter = Linter::new(config, None, None);
+        let result = linter.lint_string_wrapped(
+            "SELECT a ,b\r\nFROM c\r\n".into(),
+            None,
+            Some(true),
+            vec![RuleLT01::default().erased()],
+        );
+
+        assert_eq!(result.paths[0].files[0].fix_string(), "SELECT a, b\nFROM c\n");
+    }
+
+    #[test]
+    fn test_line_ending_detect() {
+        assert_eq!(LineEnding::detect("a\r\nb\r\nc\n"), LineEnding::CrLf);
+        assert_eq!(LineEnding::detect("a\rb\rc"), LineEnding::Cr);
+        assert_eq!(LineEnding::detect("a\r\nb\nc\n"), LineEnding::Lf);
+        assert_eq!(LineEnding::detect("a"), LineEnding::Lf);
+    }
+
+    #[test]
+    fn test_line_ending_unknown() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("line_ending".into(), Value::String("windows".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        );
+        let error = config.verify_line_ending().unwrap();
+        assert!(error.to_string().starts_with("Unknown line_ending \"windows\""));
+
+        let linter = Linter::new(config.clone(), None, None);
+        let error = linter
+            .render_string("SELECT 1\n".into(), "<string>".into(), &config, None)
+            .unwrap_err();
+        assert!(error.to_string().starts_with("Unknown line_ending"));
+    }
 }
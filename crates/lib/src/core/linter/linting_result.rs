@@ -1,6 +1,37 @@
+use std::collections::BTreeMap;
 use std::time::Instant;
 
+use serde::Serialize;
+
 use crate::core::linter::linted_dir::LintedDir;
+use crate::core::timing::{RuleTiming, RuleTimingSummary, TimingSummary};
+
+/// A serializable summary of a linted file, used for machine readable output.
+#[derive(Debug, Serialize)]
+pub struct FileRecord {
+    pub filepath: String,
+    pub violations: Vec<ViolationRecord>,
+    pub timings: BTreeMap<String, f64>,
+    pub rule_timings: Vec<RuleTiming>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ViolationRecord {
+    /// The 1-based lines and positions of the start and end of the
+    /// violation. Positions are in bytes from the start of the line, plus
+    /// one, rather than in characters.
+    pub line_no: usize,
+    pub line_pos: usize,
+    pub end_line_no: usize,
+    pub end_line_pos: usize,
+    /// The byte offsets of the start and end of the violation.
+    pub start_file_pos: usize,
+    pub end_file_pos: usize,
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub warning: bool,
+}
 
 #[derive(Debug)]
 pub struct LintingResult {
@@ -27,6 +58,62 @@ impl LintingResult {
         idx
     }
 
+    /// The total time taken, once the timer has been stopped.
+    pub fn total_time(&self) -> f64 {
+        self.total_time
+    }
+
+    /// Aggregate the step timings of all the files.
+    pub fn timing_summary(&self) -> TimingSummary {
+        let mut timing = TimingSummary::new(Some(
+            ["templating", "lexing", "parsing", "linting", "fixing"]
+                .map(ToString::to_string)
+                .to_vec(),
+        ));
+        for file in self.paths.iter().flat_map(|path| &path.files) {
+            timing.add(file.time_dict.clone());
+        }
+        timing
+    }
+
+    /// Aggregate the rule timings of all the files.
+    pub fn rule_timing_summary(&self) -> RuleTimingSummary {
+        let mut timing = RuleTimingSummary::default();
+        for file in self.paths.iter().flat_map(|path| &path.files) {
+            timing.add(&file.rule_timings);
+        }
+        timing
+    }
+
+    /// A record per linted file, with its violations and timings.
+    pub fn as_records(&self) -> Vec<FileRecord> {
+        self.paths
+            .iter()
+            .flat_map(|path| &path.files)
+            .map(|file| FileRecord {
+                filepath: file.path.clone(),
+                violations: file
+                    .violations
+                    .iter()
+                    .map(|violation| ViolationRecord {
+                        line_no: violation.line_no,
+                        line_pos: violation.line_pos,
+                        end_line_no: violation.end_line_no,
+                        end_line_pos: violation.end_line_pos,
+                        start_file_pos: violation.source_slice.start,
+                        end_file_pos: violation.source_slice.end,
+                        code: violation.rule_code().into(),
+                        name: violation.rule.as_ref().map_or("", |rule| rule.name()).into(),
+                        description: violation.description.clone(),
+                        warning: violation.warning,
+                    })
+                    .collect(),
+                timings: file.time_dict.iter().map(|(step, &time)| (step.clone(), time)).collect(),
+                rule_timings: file.rule_timings.clone(),
+            })
+            .collect()
+    }
+
     /// Stop the linting timer.
     pub(crate) fn stop_timer(&mut self) {
         self.total_time = self.start_time.elapsed().as_secs_f64();
@@ -28,11 +28,42 @@ impl Runner for SequentialRunner {
     fn run(&mut self, paths: Vec<String>, fix: bool, linter: &mut Linter) -> Vec<LintedFile> {
         let mut acc = Vec::with_capacity(paths.len());
         let rule_pack = linter.get_rulepack();
+        for warning in &rule_pack.warnings {
+            linter.warn(warning);
+        }
 
         for path in paths {
-            let rendered = linter.render_file(path);
+            // Fixing needs the parsed tree, which isn't cached.
+            let cached = linter.cache.as_ref().filter(|_| !fix).and_then(|cache| {
+                let source = std::fs::read(&path).ok()?;
+                Some((cache.key(path.as_ref(), &source), source))
+            });
+
+            if let Some((key, source)) = &cached
+                && let Some(violations) =
+                    linter.cache.as_ref().and_then(|cache| cache.get(key, &rule_pack))
+            {
+                acc.push(linter.lint_cached(path, source, violations));
+                continue;
+            }
+
+            let rendered = match linter.render_file(path.clone()) {
+                Ok(rendered) => rendered,
+                Err(skip) => {
+                    if let Some(formatter) = &mut linter.formatter {
+                        formatter.dispatch_file_skipped(&path, &skip.to_string());
+                    }
+                    continue;
+                }
+            };
             let linted_file = linter.lint_rendered(rendered, &rule_pack, fix);
 
+            if let Some(cache) = &linter.cache
+                && let Some((key, _)) = &cached
+            {
+                cache.put(key, &linted_file.violations);
+            }
+
             acc.push(linted_file);
         }
 
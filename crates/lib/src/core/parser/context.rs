@@ -1,11 +1,15 @@
+use std::collections::BTreeSet;
 use std::rc::Rc;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
+use itertools::Itertools;
 
 use super::match_result::MatchResult;
 use super::matchable::Matchable;
+use super::segments::base::{ErasedSegment, Segment, UnparsableSegment};
 use crate::core::config::FluffConfig;
 use crate::core::dialects::base::Dialect;
+use crate::core::errors::SQLParseError;
 
 #[derive(Debug)]
 pub struct ParseContext<'a> {
@@ -18,6 +22,101 @@ pub struct ParseContext<'a> {
     pub(crate) terminators: Vec<Rc<dyn Matchable>>,
     parse_cache: AHashMap<((String, (usize, usize), &'static str, usize), String), MatchResult>,
     pub(crate) indentation_config: AHashMap<String, bool>,
+    statistics: Option<ParseStatistics>,
+    furthest_failure: Option<ParseFailure>,
+    /// Whether failures are recorded, which is only done when explaining a
+    /// section which didn't parse, to keep it off the path of valid SQL.
+    track_failures: bool,
+}
+
+/// The furthest point in the file which the grammar failed to match, used
+/// to explain why a section is unparsable.
+#[derive(Debug, Clone, Hash, PartialEq)]
+pub struct ParseFailure {
+    /// The segment which was being matched, e.g. `SelectClauseSegment`.
+    pub element: String,
+    /// The grammar elements being matched at the time, outermost first.
+    pub match_stack: Vec<String>,
+    /// The first code segment which couldn't be matched.
+    pub found: ErasedSegment,
+    /// The keywords (quoted) and segment types which would have matched.
+    pub expected: BTreeSet<String>,
+}
+
+impl ParseFailure {
+    /// How many of the expected options to list before eliding the rest.
+    const MAX_EXPECTED: usize = 10;
+
+    fn position(segment: &ErasedSegment) -> Option<usize> {
+        segment.get_position_marker().map(|marker| marker.templated_slice.start)
+    }
+
+    /// The options described by the result of [`Matchable::simple`], with
+    /// keywords quoted to tell them apart from segment types.
+    pub(crate) fn simple_options(
+        (raws, types): (AHashSet<String>, AHashSet<String>),
+    ) -> impl Iterator<Item = String> {
+        raws.into_iter().map(|raw| format!("'{raw}'")).chain(types)
+    }
+
+    pub fn description(&self) -> String {
+        let mut expected = self.expected.iter().take(Self::MAX_EXPECTED).join(", ");
+        if self.expected.len() > Self::MAX_EXPECTED {
+            expected.push_str(&format!(" and {} more", self.expected.len() - Self::MAX_EXPECTED));
+        }
+        let expected =
+            if self.expected.len() == 1 { expected } else { format!("one of {expected}") };
+
+        format!(
+            "Expected {expected} while parsing {}, found {:?}.",
+            self.element,
+            self.found.get_raw().unwrap_or_default()
+        )
+    }
+}
+
+impl From<ParseFailure> for SQLParseError {
+    fn from(failure: ParseFailure) -> Self {
+        SQLParseError { description: failure.description(), segment: failure.found.into() }
+    }
+}
+
+/// Counters collected while parsing, used to find out which grammar
+/// elements are expensive.
+#[derive(Debug, Default, Clone)]
+pub struct ParseStatistics {
+    /// How many times a grammar element was entered.
+    pub match_attempts: usize,
+    /// How many times an element was entered, by element name.
+    pub match_attempts_by_name: AHashMap<String, usize>,
+    /// Lookups in the parse cache which found a previous result.
+    pub cache_hits: usize,
+    /// Lookups in the parse cache which had to match from scratch.
+    pub cache_misses: usize,
+    /// The deepest nesting of grammar elements reached.
+    pub max_depth: usize,
+    /// The match stack at the deepest point, outermost first.
+    pub deepest_match_stack: Vec<String>,
+}
+
+impl std::fmt::Display for ParseStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "==== Parse Statistics ====")?;
+        writeln!(f, "match attempts: {}", self.match_attempts)?;
+        writeln!(f, "cache hits: {}", self.cache_hits)?;
+        writeln!(f, "cache misses: {}", self.cache_misses)?;
+        writeln!(f, "max depth: {}", self.max_depth)?;
+        writeln!(f, "deepest match stack: {}", self.deepest_match_stack.join(" > "))?;
+        writeln!(f, "==== Most attempted elements ====")?;
+
+        let mut by_name: Vec<_> = self.match_attempts_by_name.iter().collect();
+        by_name.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        for (name, count) in by_name.into_iter().take(20) {
+            writeln!(f, "{name}: {count}")?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> ParseContext<'a> {
@@ -32,6 +131,9 @@ impl<'a> ParseContext<'a> {
             terminators: Vec::new(),
             parse_cache: AHashMap::new(),
             indentation_config,
+            statistics: None,
+            furthest_failure: None,
+            track_failures: false,
         }
     }
 
@@ -57,6 +159,117 @@ impl<'a> ParseContext<'a> {
         f(self)
     }
 
+    /// Start collecting [`ParseStatistics`] for the following matches.
+    pub fn collect_statistics(&mut self) {
+        self.statistics.get_or_insert_with(ParseStatistics::default);
+    }
+
+    pub fn statistics(&self) -> Option<&ParseStatistics> {
+        self.statistics.as_ref()
+    }
+
+    pub fn take_statistics(&mut self) -> Option<ParseStatistics> {
+        self.statistics.take()
+    }
+
+    /// Match with `f` while recording failures, to explain why something
+    /// didn't parse. The parse cache is bypassed, as its results weren't
+    /// matched while recording.
+    pub fn track_failures<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let track_failures = std::mem::replace(&mut self.track_failures, true);
+        let result = f(self);
+        self.track_failures = track_failures;
+        result
+    }
+
+    pub(crate) fn tracks_failures(&self) -> bool {
+        self.track_failures
+    }
+
+    /// Record that the grammar element being matched couldn't match
+    /// `segments`, keeping the failure if it's the furthest into the file so
+    /// far. Failures at the same position accumulate their expected options.
+    /// Nothing is recorded outside of [`ParseContext::track_failures`].
+    ///
+    /// `expected` is given the name of the current element and is only called
+    /// when the failure is kept. Terminal parsers set `terminal`, so that the
+    /// element reported is the segment they are part of rather than their own
+    /// reference.
+    pub(crate) fn record_failure<I: IntoIterator<Item = String>>(
+        &mut self,
+        segments: &[ErasedSegment],
+        terminal: bool,
+        expected: impl FnOnce(&str) -> I,
+    ) {
+        if !self.track_failures {
+            return;
+        }
+
+        let Some(found) = segments.iter().find(|segment| segment.is_code()) else {
+            return;
+        };
+        let Some(position) = ParseFailure::position(found) else {
+            return;
+        };
+
+        let furthest = self
+            .furthest_failure
+            .as_ref()
+            .and_then(|failure| ParseFailure::position(&failure.found));
+        if furthest.is_some_and(|furthest| furthest > position) {
+            return;
+        }
+
+        let expected = expected(&self.match_segment);
+        if let Some(failure) = &mut self.furthest_failure
+            && furthest == Some(position)
+        {
+            failure.expected.extend(expected);
+            return;
+        }
+
+        let mut match_stack = self.match_stack.clone();
+        match_stack.push(self.match_segment.clone());
+
+        let skip = if terminal { 1 } else { 0 };
+        let element = match_stack
+            .iter()
+            .rev()
+            .skip(skip)
+            .find(|name| name.ends_with("Segment"))
+            .map_or_else(|| match_stack[0].clone(), Clone::clone);
+
+        self.furthest_failure = Some(ParseFailure {
+            element,
+            match_stack,
+            found: found.clone(),
+            expected: expected.into_iter().collect(),
+        });
+    }
+
+    /// An unparsable segment for `segments`, explained by the furthest
+    /// failure if it happened within them.
+    pub(crate) fn unparsable(&self, segments: Vec<ErasedSegment>) -> UnparsableSegment {
+        let unparsable = UnparsableSegment::new(segments);
+        let start = unparsable.get_position_marker().map(|marker| marker.templated_slice.start);
+
+        match &self.furthest_failure {
+            Some(failure)
+                if start.is_some_and(|start| {
+                    ParseFailure::position(&failure.found).is_some_and(|found| found >= start)
+                }) =>
+            {
+                unparsable.with_failure(failure.clone())
+            }
+            _ => unparsable,
+        }
+    }
+
+    /// Take the furthest failure recorded since the last call.
+    pub fn take_furthest_failure(&mut self) -> Option<ParseFailure> {
+        self.furthest_failure.take()
+    }
+
     pub(crate) fn deeper_match<T>(
         &mut self,
         name: impl ToString,
@@ -69,6 +282,16 @@ impl<'a> ParseContext<'a> {
         self.match_segment = name.to_string();
         self.match_depth += 1;
 
+        if let Some(statistics) = &mut self.statistics {
+            statistics.match_attempts += 1;
+            *statistics.match_attempts_by_name.entry(self.match_segment.clone()).or_default() += 1;
+            if self.match_depth > statistics.max_depth {
+                statistics.max_depth = self.match_depth;
+                statistics.deepest_match_stack.clone_from(&self.match_stack);
+                statistics.deepest_match_stack.push(self.match_segment.clone());
+            }
+        }
+
         let (appended, terms) = self.set_terminators(clear_terminators, push_terminators);
 
         // _append, _terms = self._set_terminators(clear_terminators, push_terminators)
@@ -138,11 +361,23 @@ impl<'a> ParseContext<'a> {
     }
 
     pub(crate) fn check_parse_cache(
-        &self,
+        &mut self,
         loc_key: (String, (usize, usize), &'static str, usize),
         matcher_key: String,
     ) -> Option<MatchResult> {
-        self.parse_cache.get(&(loc_key, matcher_key)).cloned()
+        if self.track_failures {
+            return None;
+        }
+
+        let result = self.parse_cache.get(&(loc_key, matcher_key)).cloned();
+        if let Some(statistics) = &mut self.statistics {
+            if result.is_some() {
+                statistics.cache_hits += 1;
+            } else {
+                statistics.cache_misses += 1;
+            }
+        }
+        result
     }
 
     pub(crate) fn put_parse_cache(
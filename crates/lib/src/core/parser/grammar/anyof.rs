@@ -12,7 +12,7 @@ use crate::core::parser::helpers::trim_non_code_segments;
 use crate::core::parser::match_algorithms::greedy_match;
 use crate::core::parser::match_result::MatchResult;
 use crate::core::parser::matchable::Matchable;
-use crate::core::parser::segments::base::{ErasedSegment, Segment, UnparsableSegment};
+use crate::core::parser::segments::base::{ErasedSegment, Segment};
 use crate::core::parser::types::ParseMode;
 use crate::helpers::{ToErasedSegment, ToMatchable};
 
@@ -21,6 +21,7 @@ fn parse_mode_match_result(
     unmatched_segments: Vec<ErasedSegment>,
     tail: Vec<ErasedSegment>,
     parse_mode: ParseMode,
+    parse_context: &ParseContext,
 ) -> MatchResult {
     if let ParseMode::Strict = parse_mode {
         let mut unmatched = unmatched_segments;
@@ -43,7 +44,7 @@ fn parse_mode_match_result(
         "Nothing else".to_string()
     };
 
-    let unmatched_seg = UnparsableSegment::new(unmatched_segments[trim_idx..].to_vec());
+    let unmatched_seg = parse_context.unparsable(unmatched_segments[trim_idx..].to_vec());
     let mut matched = matched_segments;
     matched.extend_from_slice(&unmatched_segments[..trim_idx]);
     matched.push(unmatched_seg.to_erased_segment());
@@ -186,6 +187,7 @@ impl Matchable for AnyNumberOf {
                     unmatched_segments,
                     tail,
                     self.parse_mode,
+                    parse_context,
                 ));
             }
 
@@ -198,6 +200,7 @@ impl Matchable for AnyNumberOf {
                         unmatched_segments,
                         tail,
                         self.parse_mode,
+                        parse_context,
                     ))
                 } else {
                     // We didn't meet the hurdle
@@ -235,6 +238,7 @@ impl Matchable for AnyNumberOf {
                         chain(pre_seg, unmatched_segments).collect_vec(),
                         tail,
                         self.parse_mode,
+                        parse_context,
                     ))
                 } else {
                     // We didn't meet the hurdle
@@ -245,6 +249,7 @@ impl Matchable for AnyNumberOf {
                             .collect_vec(),
                         tail,
                         self.parse_mode,
+                        parse_context,
                     ))
                 };
             }
@@ -8,16 +8,14 @@ use uuid::Uuid;
 
 use super::conditional::Conditional;
 use crate::core::errors::SQLParseError;
-use crate::core::parser::context::ParseContext;
+use crate::core::parser::context::{ParseContext, ParseFailure};
 use crate::core::parser::helpers::{check_still_complete, trim_non_code_segments};
 use crate::core::parser::match_algorithms::{
     bracket_sensitive_look_ahead_match, greedy_match, prune_options,
 };
 use crate::core::parser::match_result::MatchResult;
 use crate::core::parser::matchable::Matchable;
-use crate::core::parser::segments::base::{
-    position_segments, ErasedSegment, Segment, UnparsableSegment,
-};
+use crate::core::parser::segments::base::{position_segments, ErasedSegment, Segment};
 use crate::core::parser::segments::bracketed::BracketedSegment;
 use crate::core::parser::segments::meta::{Indent, MetaSegment, MetaSegmentKind};
 use crate::core::parser::types::ParseMode;
@@ -276,6 +274,16 @@ impl Matchable for Sequence {
                     continue;
                 }
 
+                // Whatever comes after, such as a terminator, isn't what we
+                // needed here.
+                if parse_context.tracks_failures() {
+                    let simple = elem.simple(parse_context, None);
+                    parse_context.record_failure(&tail, false, |element| match simple {
+                        Some(simple) => ParseFailure::simple_options(simple).collect_vec(),
+                        None => vec![element.to_string()],
+                    });
+                }
+
                 if self.parse_mode == ParseMode::Strict {
                     return Ok(MatchResult::from_unmatched(segments.to_vec()));
                 }
@@ -285,7 +293,7 @@ impl Matchable for Sequence {
                 }
 
                 let matched =
-                    vec![UnparsableSegment::new(matched_segments).to_erased_segment()
+                    vec![parse_context.unparsable(matched_segments).to_erased_segment()
                         as ErasedSegment];
 
                 return Ok(MatchResult {
@@ -325,7 +333,7 @@ impl Matchable for Sequence {
 
                 matched_segments.extend(non_code_buffer);
                 matched_segments
-                    .push(UnparsableSegment::new(unmatched_segments).to_erased_segment());
+                    .push(parse_context.unparsable(unmatched_segments).to_erased_segment());
                 return Ok(MatchResult { matched_segments, unmatched_segments: tail });
             }
 
@@ -366,7 +374,7 @@ impl Matchable for Sequence {
             let (pre, unmatched_mid, post) = trim_non_code_segments(&unmatched_segments);
             if !unmatched_mid.is_empty() {
                 let unparsable_seg =
-                    UnparsableSegment::new(unmatched_mid.to_vec()).to_erased_segment();
+                    parse_context.unparsable(unmatched_mid.to_vec()).to_erased_segment();
 
                 // Here, `_position_metas` presumably modifies `matched_segments` in place or
                 // returns a modified copy. Since Rust does not have tuple
@@ -598,7 +606,7 @@ impl Matchable for Bracketed {
                         self.bracket_pairs_set.into(),
                     )?;
 
-                    Ok((content_segs, end_match))
+                    Ok::<_, SQLParseError>((content_segs, end_match))
                 })?;
 
             content_segs = segs;
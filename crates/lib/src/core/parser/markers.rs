@@ -112,11 +112,34 @@ impl PositionMarker {
         )
     }
 
+    /// This marker moved `offset` characters along, into `templated_file`.
+    ///
+    /// Used to reuse segments after an edit earlier in the file.
+    pub fn shifted(&self, offset: isize, templated_file: &TemplatedFile) -> PositionMarker {
+        let shift = |slice: &Range<usize>| {
+            slice.start.checked_add_signed(offset).unwrap()
+                ..slice.end.checked_add_signed(offset).unwrap()
+        };
+
+        PositionMarker::new(
+            shift(&self.source_slice),
+            shift(&self.templated_slice),
+            templated_file.clone(),
+            None,
+            None,
+        )
+    }
+
     /// Return the line and position of this marker in the source.
     pub fn source_position(&self) -> (usize, usize) {
         self.templated_file.get_line_pos_of_char_pos(self.templated_slice.start, true)
     }
 
+    /// Return the line and position of the end of this marker in the source.
+    pub fn source_end_position(&self) -> (usize, usize) {
+        self.templated_file.get_line_pos_of_char_pos(self.source_slice.end, true)
+    }
+
     /// Return the line and position of this marker in the source.
     pub fn templated_position(&self) -> (usize, usize) {
         self.templated_file.get_line_pos_of_char_pos(self.templated_slice.start, false)
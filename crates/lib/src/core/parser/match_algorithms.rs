@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ahash::AHashSet;
 use itertools::{chain, enumerate, multiunzip, Itertools};
 
-use super::context::ParseContext;
+use super::context::{ParseContext, ParseFailure};
 use super::helpers::trim_non_code_segments;
 use super::match_result::MatchResult;
 use super::matchable::Matchable;
@@ -80,7 +80,7 @@ pub fn prune_options(
 
         // Otherwise we have a simple option, so let's use
         // it for pruning.
-        let (simple_raws, simple_types) = simple;
+        let (simple_raws, simple_types) = &simple;
         let mut matched = false;
 
         // We want to know if the first meaningful element of the str_buff
@@ -94,13 +94,14 @@ pub fn prune_options(
             matched = true;
         }
 
-        if !matched && !first_types.intersection(&simple_types).collect_vec().is_empty() {
+        if !matched && !first_types.intersection(simple_types).collect_vec().is_empty() {
             available_options.push(opt.clone());
             matched = true;
         }
 
         if !matched {
             prune_buff.push(opt.clone());
+            parse_context.record_failure(segments, false, |_| ParseFailure::simple_options(simple));
         }
     }
 
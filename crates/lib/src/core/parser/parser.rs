@@ -1,6 +1,6 @@
-use super::context::ParseContext;
+use super::context::{ParseContext, ParseStatistics};
 use super::helpers::check_still_complete;
-use super::segments::base::ErasedSegment;
+use super::segments::base::{ErasedSegment, UnparsableSegment};
 use crate::core::config::FluffConfig;
 use crate::core::errors::SQLParseError;
 use crate::dialects::ansi::FileSegment;
@@ -9,50 +9,121 @@ use crate::dialects::ansi::FileSegment;
 pub struct Parser<'a> {
     config: &'a FluffConfig,
     root_segment: FileSegment,
+    statistics: Option<ParseStatistics>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(config: &'a FluffConfig, _dialect: Option<String>) -> Self {
-        Self { config, root_segment: FileSegment::default() }
+        Self { config, root_segment: FileSegment::default(), statistics: None }
     }
 
+    /// Parse the lexed `segments` into a tree.
+    ///
+    /// Parts of the file which can't be parsed end up as unparsable segments
+    /// in the tree, and are reported in the returned violations.
     pub fn parse(
         &mut self,
         segments: &[ErasedSegment],
         f_name: Option<String>,
         parse_statistics: bool,
-    ) -> Result<Option<ErasedSegment>, SQLParseError> {
+    ) -> Result<(Option<ErasedSegment>, Vec<SQLParseError>), SQLParseError> {
         if segments.is_empty() {
             // This should normally never happen because there will usually
             // be an end_of_file segment. It would probably only happen in
             // api use cases.
-            return Ok(None);
+            return Ok((None, Vec::new()));
         }
 
         // NOTE: This is the only time we use the parse context not in the
         // context of a context manager. That's because it's the initial
         // instantiation.
         let mut parse_cx = ParseContext::from_config(self.config);
+        if parse_statistics {
+            parse_cx.collect_statistics();
+        }
         // Kick off parsing with the root segment. The BaseFileSegment has
         // a unique entry point to facilitate exaclty this. All other segments
         // will use the standard .match()/.parse() route.
-        let root = self.root_segment.root_parse(segments, &mut parse_cx, f_name)?;
+        let (root, errors) = self.root_segment.root_parse(segments, &mut parse_cx, f_name)?;
 
         // Basic Validation, that we haven't dropped anything.
         check_still_complete(segments, &[root.clone()], &[]);
 
         if parse_statistics {
-            unimplemented!();
+            self.statistics = parse_cx.take_statistics();
+        }
+
+        let violations = Self::unparsable_violations(&root, errors);
+        Ok((root.into(), violations))
+    }
+
+    /// A violation for each unparsable section of the tree. Sections which
+    /// failed with an error are reported with that error instead.
+    pub(crate) fn unparsable_violations(
+        root: &ErasedSegment,
+        mut errors: Vec<SQLParseError>,
+    ) -> Vec<SQLParseError> {
+        let mut violations = Vec::new();
+
+        for unparsable in root.recursive_crawl(&["unparsable"], false, None, true) {
+            let span = unparsable.get_position_marker().unwrap().source_slice;
+
+            let error_idx = errors.iter().position(|error| {
+                error.segment.as_ref().and_then(|segment| segment.get_position_marker()).map_or(
+                    false,
+                    |marker| {
+                        span.start <= marker.source_slice.start
+                            && marker.source_slice.start < span.end
+                    },
+                )
+            });
+
+            if let Some(idx) = error_idx {
+                violations.push(errors.remove(idx));
+                continue;
+            }
+
+            let unparsable_segment = unparsable.as_any().downcast_ref::<UnparsableSegment>();
+
+            // Explain how far into the section we got, and what would have
+            // let us go further.
+            if let Some(failure) = unparsable_segment.and_then(UnparsableSegment::failure) {
+                violations.push(failure.clone().into());
+                continue;
+            }
+
+            let raw = unparsable.get_raw().unwrap();
+            let raw = if raw.chars().count() < 40 {
+                raw
+            } else {
+                format!("{}...", raw.chars().take(40).collect::<String>())
+            };
+
+            let mut description = format!("Found unparsable section: {raw:?}");
+            if let Some(expected) = unparsable_segment.and_then(UnparsableSegment::expected) {
+                description.push_str(&format!(". Expected {expected}."));
+            }
+
+            violations.push(SQLParseError { description, segment: unparsable.into() });
         }
 
-        Ok(root.into())
+        violations.extend(errors);
+        violations
+    }
+
+    /// The statistics gathered by the last call to [`Parser::parse`] with
+    /// `parse_statistics` set.
+    pub fn statistics(&self) -> Option<&ParseStatistics> {
+        self.statistics.as_ref()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Parser;
     use crate::core::config::FluffConfig;
     use crate::core::linter::linter::Linter;
+    use crate::core::parser::lexer::{Lexer, StringOrTemplate};
 
     #[test]
     #[ignore]
@@ -63,4 +134,67 @@ mod tests {
 
         let _ = linter.parse_string(in_str, None, None, None);
     }
+
+    #[test]
+    fn test__parser__parse_statistics() {
+        let config = FluffConfig::new(<_>::default(), None, None);
+        let lexer = Lexer::new(&config, None);
+        let (tokens, _) =
+            lexer.lex(StringOrTemplate::String("SELECT a FROM b WHERE c = 1".into())).unwrap();
+
+        let mut parser = Parser::new(&config, None);
+        let (_, violations) = parser.parse(&tokens, None, true).unwrap();
+        assert!(violations.is_empty());
+
+        let statistics = parser.statistics().unwrap();
+        assert!(statistics.match_attempts > 0);
+        assert!(statistics.cache_hits + statistics.cache_misses > 0);
+        assert_eq!(statistics.deepest_match_stack.len(), statistics.max_depth + 1);
+        assert_eq!(statistics.deepest_match_stack[0], "File");
+    }
+
+    #[test]
+    fn test__parser__recover_at_delimiter() {
+        let config = FluffConfig::new(<_>::default(), None, None);
+        let lexer = Lexer::new(&config, None);
+        let (tokens, _) = lexer
+            .lex(StringOrTemplate::String("SELECT 1;\nNOT VALID SQL;\nSELECT 2;\n".into()))
+            .unwrap();
+
+        let (tree, violations) = Parser::new(&config, None).parse(&tokens, None, false).unwrap();
+        let tree = tree.unwrap();
+
+        assert_eq!(tree.get_raw().unwrap(), "SELECT 1;\nNOT VALID SQL;\nSELECT 2;\n");
+        assert_eq!(tree.recursive_crawl(&["select_statement"], true, None, true).len(), 2);
+
+        let unparsable = tree.recursive_crawl(&["unparsable"], true, None, true);
+        assert_eq!(unparsable.len(), 1);
+        assert_eq!(unparsable[0].get_raw().unwrap(), "NOT VALID SQL");
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].description,
+            "Expected one of '(', 'ALTER', 'BEGIN', 'COMMIT', 'CREATE', 'DELETE', 'DESCRIBE', \
+             'DROP', 'END', 'EXPLAIN' and 14 more while parsing File, found \"NOT\"."
+        );
+    }
+
+    #[test]
+    fn test__parser__expected_found() {
+        let config = FluffConfig::new(<_>::default(), None, None);
+        let lexer = Lexer::new(&config, None);
+        let (tokens, _) =
+            lexer.lex(StringOrTemplate::String("INSERT INTO t SELECT;\n".into())).unwrap();
+
+        let (_, violations) = Parser::new(&config, None).parse(&tokens, None, false).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].segment.as_ref().unwrap().get_raw().unwrap(), ";");
+        assert_eq!(
+            violations[0].description,
+            "Expected one of 'EXCEPT', 'FETCH', 'FROM', 'INTERSECT', 'LIMIT', 'MINUS', 'ORDER', \
+             'OVERLAPS', 'UNION', 'WHERE' and 2 more while parsing SelectClauseSegment, found \
+             \";\"."
+        );
+    }
 }
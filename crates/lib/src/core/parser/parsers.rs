@@ -96,7 +96,7 @@ impl Matchable for TypedParser {
     fn match_segments(
         &self,
         segments: &[ErasedSegment],
-        _parse_context: &mut ParseContext,
+        parse_context: &mut ParseContext,
     ) -> Result<MatchResult, SQLParseError> {
         if !segments.is_empty() {
             let segment = &*segments[0];
@@ -105,6 +105,7 @@ impl Matchable for TypedParser {
             }
         };
 
+        parse_context.record_failure(segments, true, |_| [self.template.clone()]);
         Ok(MatchResult::from_unmatched(segments.to_vec()))
     }
 }
@@ -195,7 +196,7 @@ impl Matchable for StringParser {
     fn match_segments(
         &self,
         segments: &[ErasedSegment],
-        _parse_context: &mut ParseContext,
+        parse_context: &mut ParseContext,
     ) -> Result<MatchResult, SQLParseError> {
         if !segments.is_empty() {
             let segment = &*segments[0];
@@ -204,6 +205,7 @@ impl Matchable for StringParser {
             }
         };
 
+        parse_context.record_failure(segments, true, |_| [format!("'{}'", self.template)]);
         Ok(MatchResult::from_unmatched(segments.to_vec()))
     }
 
@@ -315,7 +317,7 @@ impl Matchable for RegexParser {
     fn match_segments(
         &self,
         segments: &[ErasedSegment],
-        _parse_context: &mut ParseContext,
+        parse_context: &mut ParseContext,
     ) -> Result<MatchResult, SQLParseError> {
         if !segments.is_empty() {
             let segment = &*segments[0];
@@ -324,6 +326,7 @@ impl Matchable for RegexParser {
             }
         }
 
+        parse_context.record_failure(segments, true, |element| [element.to_string()]);
         Ok(MatchResult::from_unmatched(segments.to_vec()))
     }
 
@@ -415,7 +418,7 @@ impl Matchable for MultiStringParser {
     fn match_segments(
         &self,
         segments: &[ErasedSegment],
-        _parse_context: &mut ParseContext,
+        parse_context: &mut ParseContext,
     ) -> Result<MatchResult, SQLParseError> {
         if !segments.is_empty() {
             let segment = &*segments[0];
@@ -424,6 +427,9 @@ impl Matchable for MultiStringParser {
             }
         }
 
+        parse_context.record_failure(segments, true, |_| {
+            self.templates.iter().map(|template| format!("'{template}'"))
+        });
         Ok(MatchResult::from_unmatched(segments.to_vec()))
     }
 
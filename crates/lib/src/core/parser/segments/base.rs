@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::core::dialects::base::Dialect;
+use crate::core::parser::context::ParseFailure;
 use crate::core::parser::markers::PositionMarker;
 use crate::core::parser::matchable::Matchable;
 use crate::core::parser::segments::fix::{AnchorEditInfo, FixPatch, SourceFix};
@@ -1159,11 +1160,11 @@ impl Segment for NewlineSegment {
     }
 
     fn set_position_marker(&mut self, position_marker: Option<PositionMarker>) {
-        let Some(_position_marker) = position_marker else {
+        let Some(position_marker) = position_marker else {
             return;
         };
 
-        dbg!("self.position_marker = position_marker;");
+        self.position_maker = position_marker;
     }
 
     fn segments(&self) -> &[ErasedSegment] {
@@ -1435,22 +1436,63 @@ pub struct UnparsableSegment {
     uuid: Uuid,
     pub segments: Vec<ErasedSegment>,
     position_marker: Option<PositionMarker>,
+    expected: String,
+    failure: Option<ParseFailure>,
 }
 
 impl UnparsableSegment {
     pub fn new(segments: Vec<ErasedSegment>) -> Self {
-        let mut this = Self { uuid: Uuid::new_v4(), segments, position_marker: None };
+        let mut this = Self {
+            uuid: Uuid::new_v4(),
+            segments,
+            position_marker: None,
+            expected: String::new(),
+            failure: None,
+        };
         this.uuid = Uuid::new_v4();
         this.set_position_marker(pos_marker(&this).into());
         this
     }
+
+    /// Record the name of the grammar which failed to match.
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = expected.into();
+        self
+    }
+
+    /// The name of the grammar which failed to match, if known.
+    pub fn expected(&self) -> Option<&str> {
+        (!self.expected.is_empty()).then_some(self.expected.as_str())
+    }
+
+    /// Record the furthest point the grammar reached before failing.
+    pub fn with_failure(mut self, failure: ParseFailure) -> Self {
+        self.failure = failure.into();
+        self
+    }
+
+    /// The furthest point the grammar reached before failing, if known.
+    pub fn failure(&self) -> Option<&ParseFailure> {
+        self.failure.as_ref()
+    }
 }
 
 impl Segment for UnparsableSegment {
+    fn new(&self, segments: Vec<ErasedSegment>) -> ErasedSegment {
+        let mut this = self.clone();
+        this.segments = segments;
+        this.set_position_marker(pos_marker(&this).into());
+        this.to_erased_segment()
+    }
+
     fn get_type(&self) -> &'static str {
         "unparsable"
     }
 
+    fn class_types(&self) -> AHashSet<String> {
+        ["unparsable"].map(ToOwned::to_owned).into_iter().collect()
+    }
+
     fn get_position_marker(&self) -> Option<PositionMarker> {
         self.position_marker.clone()
     }
@@ -1468,6 +1510,32 @@ impl Segment for UnparsableSegment {
     }
 }
 
+/// A copy of `segment` and its children moved `offset` characters along, into
+/// `templated_file`.
+pub fn shift_segment(
+    segment: &ErasedSegment,
+    offset: isize,
+    templated_file: &TemplatedFile,
+) -> ErasedSegment {
+    let mut shifted = if segment.segments().is_empty() {
+        segment.clone_box()
+    } else {
+        segment.new(
+            segment
+                .segments()
+                .iter()
+                .map(|child| shift_segment(child, offset, templated_file))
+                .collect(),
+        )
+    };
+
+    let position_marker =
+        segment.get_position_marker().map(|marker| marker.shifted(offset, templated_file));
+    shifted.get_mut().set_position_marker(position_marker);
+
+    shifted
+}
+
 pub fn pos_marker(this: &dyn Segment) -> PositionMarker {
     let markers: Vec<_> =
         this.segments().iter().flat_map(|seg| seg.get_position_marker()).collect();
@@ -50,8 +50,8 @@ impl Segment for LiteralSegment {
         self.position_maker.clone().into()
     }
 
-    fn set_position_marker(&mut self, _position_marker: Option<PositionMarker>) {
-        todo!()
+    fn set_position_marker(&mut self, position_marker: Option<PositionMarker>) {
+        self.position_maker = position_marker.unwrap();
     }
 
     fn segments(&self) -> &[ErasedSegment] {
@@ -119,8 +119,8 @@ impl Segment for ComparisonOperatorSegment {
         self.position_maker.clone().into()
     }
 
-    fn set_position_marker(&mut self, _position_marker: Option<PositionMarker>) {
-        todo!()
+    fn set_position_marker(&mut self, position_marker: Option<PositionMarker>) {
+        self.position_maker = position_marker.unwrap();
     }
 
     fn segments(&self) -> &[ErasedSegment] {
@@ -61,8 +61,8 @@ impl Segment for RawSegment {
         self.position_marker.clone()
     }
 
-    fn set_position_marker(&mut self, _position_marker: Option<PositionMarker>) {
-        todo!()
+    fn set_position_marker(&mut self, position_marker: Option<PositionMarker>) {
+        self.position_marker = position_marker;
     }
 
     fn get_raw_segments(&self) -> Vec<ErasedSegment> {
@@ -5,7 +5,7 @@ use std::rc::Rc;
 
 use ahash::{AHashMap, AHashSet};
 use anymap::AnyMap;
-use itertools::chain;
+use itertools::{chain, Itertools};
 
 use super::context::RuleContext;
 use super::crawlers::{BaseCrawler, Crawler};
@@ -13,7 +13,9 @@ use crate::core::config::{FluffConfig, Value};
 use crate::core::dialects::base::Dialect;
 use crate::core::errors::SQLLintError;
 use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::templaters::base::TemplatedFile;
 use crate::helpers::{Config, IndexMap};
+use crate::rules::docs::{self, ConfigOption};
 
 #[derive(Clone)]
 pub struct LintResult {
@@ -45,7 +47,11 @@ impl LintResult {
             self.description.clone().unwrap_or_else(|| rule.description().to_string());
 
         SQLLintError::new(description.as_str(), anchor)
-            .config(|this| this.rule = rule.into())
+            .config(|this| {
+                this.rule_code = rule.code().into();
+                this.rule = rule.into();
+                this.fixes.clone_from(&self.fixes);
+            })
             .into()
     }
 }
@@ -244,19 +250,46 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static {
         "main"
     }
 
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
 
-    fn config_ref(&self) -> &'static str {
+    fn config_ref(&self) -> &str {
         self.name()
     }
 
-    fn description(&self) -> &'static str;
+    fn description(&self) -> &str;
+
+    /// The documentation of the rule in Markdown, which usually shows an
+    /// anti-pattern and the best practice for it.
+    fn long_description(&self) -> &'static str {
+        docs::long_description(self.code())
+    }
+
+    /// The SQL which the rule flags, from its documentation.
+    fn anti_pattern(&self) -> Option<&'static str> {
+        docs::sql_example(self.long_description(), "Anti-pattern")
+    }
+
+    /// The SQL which the rule suggests instead, from its documentation.
+    fn best_practice(&self) -> Option<&'static str> {
+        docs::sql_example(self.long_description(), "Best practice")
+    }
+
+    /// The options in the rule's section of the config.
+    fn config_options(&self) -> &'static [ConfigOption] {
+        docs::config_options(self.config_ref())
+    }
 
     fn groups(&self) -> &'static [&'static str] {
         &["all"]
     }
 
-    fn code(&self) -> &'static str {
+    /// Other references the rule can be selected by, such as the codes it
+    /// had in SQLFluff before rules were grouped.
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn code(&self) -> &str {
         let name = std::any::type_name::<Self>();
         name.split("::").last().unwrap().strip_prefix("Rule").unwrap_or(name)
     }
@@ -269,20 +302,31 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static {
 
     fn crawl_behaviour(&self) -> Crawler;
 
+    /// Run the rule over `tree`, the parsed contents of the file at `path`,
+    /// returning its violations, its fixes and the number of segments it was
+    /// evaluated on.
+    ///
+    /// Unless `ignore_templated_areas` is turned off, violations anchored in
+    /// templated sections of the file are dropped, along with their fixes.
     fn crawl(
         &self,
         dialect: &Dialect,
         fix: bool,
         tree: ErasedSegment,
+        templated_file: &TemplatedFile,
+        path: &str,
         config: &FluffConfig,
-    ) -> (Vec<SQLLintError>, Vec<LintFix>) {
+    ) -> (Vec<SQLLintError>, Vec<LintFix>, usize) {
+        let ignore_templated_areas =
+            config.get("ignore_templated_areas", "core").as_bool().unwrap_or(true);
+
         let root_context = RuleContext {
             dialect,
             fix,
             config: Some(config),
             segment: tree,
-            templated_file: <_>::default(),
-            path: <_>::default(),
+            templated_file: Some(templated_file.clone()),
+            path: Some(path.to_string()),
             parent_stack: <_>::default(),
             raw_stack: <_>::default(),
             memory: Rc::new(RefCell::new(AnyMap::new())),
@@ -291,7 +335,10 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static {
         let mut vs = Vec::new();
         let mut fixes = Vec::new();
 
-        for context in self.crawl_behaviour().crawl(root_context) {
+        let contexts = self.crawl_behaviour().crawl(root_context);
+        let crawled_segments = contexts.len();
+
+        for context in contexts {
             let resp = self.eval(context);
 
             let mut new_lerrs = Vec::new();
@@ -301,6 +348,10 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static {
                 // Assume this means no problems (also means no memory)
             } else {
                 for elem in resp {
+                    if ignore_templated_areas && is_templated(&elem, templated_file) {
+                        continue;
+                    }
+
                     self.process_lint_result(elem, &mut new_lerrs, &mut new_fixes);
                 }
             }
@@ -310,7 +361,7 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static {
             fixes.extend(new_fixes);
         }
 
-        (vs, fixes)
+        (vs, fixes, crawled_segments)
     }
 
     fn process_lint_result(
@@ -333,14 +384,24 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static {
 
 dyn_clone::clone_trait_object!(Rule);
 
+/// Whether the anchor of `result` comes from a templated section of the file,
+/// rather than literally from the source.
+fn is_templated(result: &LintResult, templated_file: &TemplatedFile) -> bool {
+    result
+        .anchor
+        .as_ref()
+        .and_then(|anchor| anchor.get_position_marker())
+        .is_some_and(|marker| !templated_file.is_source_slice_literal(&marker.source_slice))
+}
+
 #[derive(Debug, Clone)]
 pub struct ErasedRule {
     erased: Rc<dyn Rule>,
 }
 
 impl PartialEq for ErasedRule {
-    fn eq(&self, _other: &Self) -> bool {
-        unimplemented!()
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
     }
 }
 
@@ -366,29 +427,102 @@ impl<T: Rule> Erased for T {
     }
 }
 
+/// A rule along with the references it can be selected by in `rules` and
+/// `exclude_rules`.
+#[derive(Clone)]
 pub struct RuleManifest {
-    pub code: &'static str,
-    pub name: &'static str,
-    pub description: &'static str,
     pub groups: &'static [&'static str],
     pub aliases: Vec<&'static str>,
     pub rule_class: ErasedRule,
 }
 
+impl RuleManifest {
+    pub fn new(rule: ErasedRule) -> Self {
+        Self { groups: rule.groups(), aliases: rule.aliases().to_vec(), rule_class: rule }
+    }
+
+    pub fn code(&self) -> &str {
+        self.rule_class.code()
+    }
+
+    pub fn name(&self) -> &str {
+        self.rule_class.name()
+    }
+
+    pub fn description(&self) -> &str {
+        self.rule_class.description()
+    }
+
+    /// Also select the rule by `aliases`, on top of those the rule gives.
+    pub fn aliases(mut self, aliases: &[&'static str]) -> Self {
+        self.aliases.extend_from_slice(aliases);
+        self
+    }
+}
+
+impl From<ErasedRule> for RuleManifest {
+    fn from(rule: ErasedRule) -> Self {
+        Self::new(rule)
+    }
+}
+
 pub struct RulePack {
     pub(crate) rules: Vec<ErasedRule>,
-    _reference_map: AHashMap<&'static str, AHashSet<&'static str>>,
+    /// Problems with the config found while selecting the rules, for the
+    /// user to be told about.
+    pub warnings: Vec<String>,
+}
+
+impl RulePack {
+    pub fn rules(&self) -> Vec<ErasedRule> {
+        self.rules.clone()
+    }
 }
 
 pub struct RuleSet {
     pub(crate) _name: String,
     pub(crate) _config_info: AHashMap<String, String>,
-    pub(crate) register: IndexMap<&'static str, RuleManifest>,
+    pub(crate) register: IndexMap<String, RuleManifest>,
+    /// Rules which couldn't be registered, for the user to be told about.
+    pub(crate) warnings: Vec<String>,
 }
 
 impl RuleSet {
-    fn rule_reference_map(&self) -> AHashMap<&'static str, AHashSet<&'static str>> {
-        let valid_codes: AHashSet<_> = self.register.keys().cloned().collect();
+    /// Add `rule` to the set, unless its code is already taken.
+    pub fn register_rule(&mut self, rule: impl Into<RuleManifest>) {
+        let rule = rule.into();
+
+        if let Some(manifest) = self.register.get(rule.code()) {
+            self.warnings.push(format!(
+                "Rule {} ({}) is not registered, as its code is already used by {}.",
+                rule.code(),
+                rule.name(),
+                manifest.name()
+            ));
+            return;
+        }
+
+        self.register.insert(rule.code().to_owned(), rule);
+    }
+
+    /// The rules in the set, in the order they were registered.
+    pub fn manifests(&self) -> impl Iterator<Item = &RuleManifest> {
+        self.register.values()
+    }
+
+    /// The rules referenced by `references`, in the same way as in `rules`
+    /// and `exclude_rules`.
+    pub fn select(&self, references: Vec<String>) -> Vec<&RuleManifest> {
+        let reference_map = self.rule_reference_map(&mut Vec::new());
+        let codes = self.expand_rule_refs(references, &reference_map, &mut Vec::new());
+        self.register.values().filter(|manifest| codes.contains(manifest.code())).collect()
+    }
+
+    /// Every reference a rule can be selected by, mapped to the codes of the
+    /// rules it selects. References which collide with one another are
+    /// left out, with a warning pushed to `warnings`.
+    fn rule_reference_map(&self, warnings: &mut Vec<String>) -> AHashMap<&str, AHashSet<&str>> {
+        let valid_codes: AHashSet<_> = self.register.keys().map(String::as_str).collect();
 
         let reference_map: AHashMap<_, AHashSet<_>> =
             valid_codes.iter().map(|&code| (code, AHashSet::from([code]))).collect();
@@ -396,7 +530,10 @@ impl RuleSet {
         let name_map = {
             let mut name_map = AHashMap::new();
             for manifest in self.register.values() {
-                name_map.entry(manifest.name).or_insert_with(AHashSet::new).insert(manifest.code);
+                name_map
+                    .entry(manifest.name())
+                    .or_insert_with(AHashSet::new)
+                    .insert(manifest.code());
             }
             name_map
         };
@@ -407,48 +544,48 @@ impl RuleSet {
         };
 
         if !name_collisions.is_empty() {
-            tracing::warn!(
+            warnings.push(format!(
                 "The following defined rule names were found which collide with codes. Those \
                  names will not be available for selection: {name_collisions:?}",
-            );
+            ));
         }
 
         let reference_map: AHashMap<_, _> = chain(name_map, reference_map).collect();
 
-        let mut group_map: AHashMap<_, AHashSet<&'static str>> = AHashMap::new();
+        let mut group_map: AHashMap<_, AHashSet<&str>> = AHashMap::new();
         for manifest in self.register.values() {
             for group in manifest.groups {
                 if let Some(codes) = reference_map.get(*group) {
-                    tracing::warn!(
+                    warnings.push(format!(
                         "Rule {} defines group '{}' which is already defined as a name or code of \
                          {:?}. This group will not be available for use as a result of this \
                          collision.",
-                        manifest.code,
+                        manifest.code(),
                         group,
                         codes
-                    );
+                    ));
                 } else {
-                    group_map.entry(*group).or_insert_with(AHashSet::new).insert(manifest.code);
+                    group_map.entry(*group).or_insert_with(AHashSet::new).insert(manifest.code());
                 }
             }
         }
 
         let reference_map: AHashMap<_, _> = chain(group_map, reference_map).collect();
 
-        let mut alias_map: AHashMap<_, AHashSet<&'static str>> = AHashMap::new();
+        let mut alias_map: AHashMap<_, AHashSet<&str>> = AHashMap::new();
         for manifest in self.register.values() {
             for alias in &manifest.aliases {
                 if let Some(codes) = reference_map.get(alias) {
-                    tracing::warn!(
+                    warnings.push(format!(
                         "Rule {} defines alias '{}' which is already defined as a name, code or \
                          group of {:?}. This alias will not be available for use as a result of \
                          this collision.",
-                        manifest.code,
+                        manifest.code(),
                         alias,
                         codes
-                    );
+                    ));
                 } else {
-                    alias_map.entry(*alias).or_insert_with(AHashSet::new).insert(manifest.code);
+                    alias_map.entry(*alias).or_insert_with(AHashSet::new).insert(manifest.code());
                 }
             }
         }
@@ -456,22 +593,50 @@ impl RuleSet {
         chain(alias_map, reference_map).collect()
     }
 
-    fn expand_rule_refs(
+    /// The codes of the rules referenced by `glob_list`, by code, name, group
+    /// or alias, or by a glob pattern of any of them such as `LT*` or
+    /// `layout.*`.
+    ///
+    /// References which match no rule are skipped with a warning pushed to
+    /// `warnings`, as they're likely a typo or a rule which this version
+    /// doesn't have.
+    fn expand_rule_refs<'a>(
         &self,
         glob_list: Vec<String>,
-        reference_map: &AHashMap<&'static str, AHashSet<&'static str>>,
-    ) -> AHashSet<&'static str> {
+        reference_map: &AHashMap<&'a str, AHashSet<&'a str>>,
+        warnings: &mut Vec<String>,
+    ) -> AHashSet<&'a str> {
         let mut expanded_rule_set = AHashSet::new();
 
         for r in glob_list {
-            expanded_rule_set.extend(reference_map[r.as_str()].clone());
+            if let Some(codes) = reference_map.get(r.as_str()) {
+                expanded_rule_set.extend(codes);
+                continue;
+            }
+
+            let matched = glob::Pattern::new(&r).ok().map(|pattern| {
+                reference_map
+                    .iter()
+                    .filter(|(reference, _)| pattern.matches(reference))
+                    .flat_map(|(_, codes)| codes)
+                    .collect_vec()
+            });
+
+            match matched {
+                Some(codes) if !codes.is_empty() => expanded_rule_set.extend(codes),
+                _ => warnings.push(format!(
+                    "Rule reference {r:?} in the config matches no rule, so it's ignored."
+                )),
+            }
         }
 
         expanded_rule_set
     }
 
-    pub(crate) fn get_rulepack(&self, config: &FluffConfig) -> RulePack {
-        let reference_map = self.rule_reference_map();
+    /// The rules selected by `config`, configured by their sections of it.
+    pub fn get_rulepack(&self, config: &FluffConfig) -> RulePack {
+        let mut warnings = self.warnings.clone();
+        let reference_map = self.rule_reference_map(&mut warnings);
         let rules = config.get_section("rules");
         let keylist = self.register.keys();
         let mut instantiated_rules = Vec::with_capacity(keylist.len());
@@ -486,12 +651,14 @@ impl RuleSet {
             None => Vec::new(),
         };
 
-        let expanded_allowlist = self.expand_rule_refs(allowlist, &reference_map);
-        let expanded_denylist = self.expand_rule_refs(denylist, &reference_map);
+        let expanded_allowlist = self.expand_rule_refs(allowlist, &reference_map, &mut warnings);
+        let expanded_denylist = self.expand_rule_refs(denylist, &reference_map, &mut warnings);
 
         let keylist: Vec<_> = keylist
             .into_iter()
-            .filter(|&&r| expanded_allowlist.contains(r) && !expanded_denylist.contains(r))
+            .filter(|r| {
+                expanded_allowlist.contains(r.as_str()) && !expanded_denylist.contains(r.as_str())
+            })
             .collect();
 
         for code in keylist {
@@ -506,6 +673,70 @@ impl RuleSet {
             instantiated_rules.push(rule.load_from_config(specific_rule_config));
         }
 
-        RulePack { rules: instantiated_rules, _reference_map: reference_map }
+        RulePack { rules: instantiated_rules, warnings }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::RulePack;
+    use crate::core::config::{FluffConfig, Value};
+    use crate::rules::get_ruleset;
+
+    fn rule_pack(rules: &str, exclude_rules: &str) -> RulePack {
+        let mut core = vec![("rules".into(), Value::String(rules.into()))];
+        if !exclude_rules.is_empty() {
+            core.push(("exclude_rules".into(), Value::String(exclude_rules.into())));
+        }
+        let config = FluffConfig::new(
+            [("core".into(), Value::Map(core.into_iter().collect()))].into(),
+            None,
+            None,
+        );
+
+        get_ruleset().get_rulepack(&config)
+    }
+
+    fn selected(rules: &str, exclude_rules: &str) -> Vec<String> {
+        rule_pack(rules, exclude_rules)
+            .rules
+            .iter()
+            .map(|rule| rule.code().to_owned())
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn test__rules__base__rule_references() {
+        assert_eq!(selected("L010,L036", ""), ["CP01", "LT09"]);
+        assert_eq!(selected("L001,L005", ""), ["LT01"]);
+        assert_eq!(selected("capitalisation.keywords", ""), ["CP01"]);
+        assert_eq!(
+            selected("LT0*", ""),
+            ["LT01", "LT02", "LT03", "LT04", "LT05", "LT06", "LT07", "LT08", "LT09"]
+        );
+        assert_eq!(selected("references.*", "RF0[2-6]"), ["RF01"]);
+        assert_eq!(
+            selected("layout.*", "L036,LT1?"),
+            ["LT01", "LT02", "LT03", "LT04", "LT05", "LT06", "LT07", "LT08"]
+        );
+    }
+
+    #[test]
+    fn test__rules__base__unknown_rule_references() {
+        assert_eq!(selected("CP01,XX99,nope.*", "L999"), ["CP01"]);
+        assert!(selected("XX99", "").is_empty());
+
+        assert_eq!(
+            rule_pack("CP01,XX99,nope.*", "L999").warnings,
+            [
+                "Rule reference \"XX99\" in the config matches no rule, so it's ignored.",
+                "Rule reference \"nope.*\" in the config matches no rule, so it's ignored.",
+                "Rule reference \"L999\" in the config matches no rule, so it's ignored.",
+            ]
+        );
+        assert!(rule_pack("CP01", "").warnings.is_empty());
     }
 }
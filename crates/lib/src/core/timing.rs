@@ -1,32 +1,186 @@
 /// Timing summary class
 use ahash::AHashMap;
+use indexmap::IndexMap;
+use itertools::Itertools;
+use serde::Serialize;
 
 /// An object for tracking the timing of similar steps across many files.
-#[allow(unused_variables, dead_code)]
+#[derive(Debug, Default)]
 pub struct TimingSummary {
     steps: Option<Vec<String>>,
     timings: Vec<AHashMap<String, f64>>,
 }
 
 impl TimingSummary {
-    #[allow(dead_code)]
-    fn new(steps: Option<Vec<String>>) -> TimingSummary {
+    pub fn new(steps: Option<Vec<String>>) -> TimingSummary {
         TimingSummary { steps, timings: Vec::new() }
     }
 
-    /// Add a timing to the summary.
-    #[allow(dead_code)]
-    fn add_timing(&mut self, _step: String, _timing: f64) {
-        if self.steps.is_none() {
-            self.steps = Some(Vec::new());
-        }
+    /// Add the timings of a single file to the summary.
+    pub fn add(&mut self, timing_dict: AHashMap<String, f64>) {
+        self.timings.push(timing_dict);
     }
 
     /// Generate summary for display.
-    #[allow(dead_code)]
-    fn summary(&self) -> Summary {
-        panic!("Not implemented")
+    ///
+    /// Steps are reported in the order they were given, or alphabetically if
+    /// no steps were given.
+    pub fn summary(&self) -> IndexMap<String, Summary> {
+        let steps = match &self.steps {
+            Some(steps) => steps.clone(),
+            None => self
+                .timings
+                .iter()
+                .flat_map(|timing_dict| timing_dict.keys().cloned())
+                .sorted()
+                .dedup()
+                .collect(),
+        };
+
+        steps
+            .into_iter()
+            .filter_map(|step| {
+                let values = self.timings.iter().filter_map(|timing_dict| timing_dict.get(&step));
+                Summary::from_values(values.copied()).map(|summary| (step, summary))
+            })
+            .collect()
+    }
+}
+
+/// A single run of a rule over a file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleTiming {
+    pub code: String,
+    pub name: String,
+    /// Wall time of the crawl, in seconds.
+    pub time: f64,
+    /// How many segments the rule was evaluated on.
+    pub crawled_segments: usize,
+}
+
+/// An object for tracking the timing of rules across many files.
+#[derive(Debug, Default)]
+pub struct RuleTimingSummary {
+    timings: Vec<RuleTiming>,
+}
+
+impl RuleTimingSummary {
+    /// Add the rule timings of a single file.
+    pub fn add(&mut self, rule_timings: &[RuleTiming]) {
+        self.timings.extend_from_slice(rule_timings);
+    }
+
+    /// Generate summary for display, keyed by rule code and sorted with the
+    /// slowest rule first.
+    pub fn summary(&self) -> IndexMap<String, RuleSummary> {
+        let mut by_code: IndexMap<&str, Vec<&RuleTiming>> = IndexMap::new();
+        for timing in &self.timings {
+            by_code.entry(&timing.code).or_default().push(timing);
+        }
+
+        let mut summary: IndexMap<String, RuleSummary> = by_code
+            .into_iter()
+            .filter_map(|(code, timings)| {
+                let time = Summary::from_values(timings.iter().map(|timing| timing.time))?;
+                let summary = RuleSummary {
+                    name: timings[0].name.clone(),
+                    time,
+                    crawled_segments: timings.iter().map(|timing| timing.crawled_segments).sum(),
+                };
+                Some((code.to_string(), summary))
+            })
+            .collect();
+        summary.sort_by(|_, a, _, b| b.time.sum.total_cmp(&a.time.sum));
+        summary
     }
 }
 
-pub struct Summary {}
+/// Aggregated timings of a single rule.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleSummary {
+    pub name: String,
+    pub time: Summary,
+    /// Total number of segments the rule was evaluated on.
+    pub crawled_segments: usize,
+}
+
+/// Aggregated timings of a single step.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Summary {
+    pub count: usize,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    pub avg: f64,
+}
+
+impl Summary {
+    fn from_values(values: impl IntoIterator<Item = f64>) -> Option<Summary> {
+        let mut values = values.into_iter();
+        let first = values.next()?;
+
+        let mut summary = Summary { count: 1, sum: first, min: first, max: first, avg: first };
+        for value in values {
+            summary.count += 1;
+            summary.sum += value;
+            summary.min = summary.min.min(value);
+            summary.max = summary.max.max(value);
+        }
+        summary.avg = summary.sum / summary.count as f64;
+
+        Some(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+
+    use super::{RuleTiming, RuleTimingSummary, Summary, TimingSummary};
+
+    #[test]
+    fn test__timing__summary() {
+        let mut timing = TimingSummary::new(Some(vec!["lexing".into(), "parsing".into()]));
+        timing.add(AHashMap::from_iter([("lexing".into(), 1.0), ("parsing".into(), 4.0)]));
+        timing.add(AHashMap::from_iter([("lexing".into(), 3.0)]));
+
+        let summary = timing.summary();
+        assert_eq!(summary["lexing"], Summary { count: 2, sum: 4.0, min: 1.0, max: 3.0, avg: 2.0 });
+        assert_eq!(
+            summary["parsing"],
+            Summary { count: 1, sum: 4.0, min: 4.0, max: 4.0, avg: 4.0 }
+        );
+    }
+
+    #[test]
+    fn test__timing__summary_without_steps() {
+        let mut timing = TimingSummary::default();
+        timing.add(AHashMap::from_iter([("parsing".into(), 1.0), ("lexing".into(), 2.0)]));
+
+        assert_eq!(timing.summary().keys().collect::<Vec<_>>(), ["lexing", "parsing"]);
+    }
+
+    #[test]
+    fn test__timing__rule_summary() {
+        let rule_timing = |code: &str, name: &str, time, crawled_segments| RuleTiming {
+            code: code.into(),
+            name: name.into(),
+            time,
+            crawled_segments,
+        };
+
+        let mut timing = RuleTimingSummary::default();
+        timing.add(&[
+            rule_timing("LT01", "layout.spacing", 1.0, 10),
+            rule_timing("AM01", "ambiguous.distinct", 3.0, 2),
+        ]);
+        timing.add(&[rule_timing("LT01", "layout.spacing", 1.5, 5)]);
+
+        let summary = timing.summary();
+        assert_eq!(summary.keys().collect::<Vec<_>>(), ["AM01", "LT01"]);
+        assert_eq!(summary["LT01"].name, "layout.spacing");
+        assert_eq!(summary["LT01"].time.count, 2);
+        assert_eq!(summary["LT01"].time.sum, 2.5);
+        assert_eq!(summary["LT01"].crawled_segments, 15);
+    }
+}
@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 
 use ahash::AHashSet;
-use itertools::{chain, Itertools};
+use itertools::Itertools;
 use uuid::Uuid;
 
 use super::ansi_keywords::{ANSI_RESERVED_KEYWORDS, ANSI_UNRESERVED_KEYWORDS};
@@ -20,14 +20,13 @@ use crate::core::parser::grammar::delimited::Delimited;
 use crate::core::parser::grammar::sequence::{Bracketed, Sequence};
 use crate::core::parser::lexer::{Matcher, RegexLexer, StringLexer};
 use crate::core::parser::markers::PositionMarker;
-use crate::core::parser::match_result::MatchResult;
 use crate::core::parser::matchable::Matchable;
 use crate::core::parser::parsers::{MultiStringParser, RegexParser, StringParser, TypedParser};
 use crate::core::parser::segments::base::{
     pos_marker, CloneSegment, CodeSegment, CodeSegmentNewArgs, CommentSegment,
     CommentSegmentNewArgs, ErasedSegment, IdentifierSegment, NewlineSegment, NewlineSegmentNewArgs,
-    Segment, SegmentConstructorFn, SymbolSegment, SymbolSegmentNewArgs, UnparsableSegment,
-    WhitespaceSegment, WhitespaceSegmentNewArgs,
+    Segment, SegmentConstructorFn, SymbolSegment, SymbolSegmentNewArgs, WhitespaceSegment,
+    WhitespaceSegmentNewArgs,
 };
 use crate::core::parser::segments::common::{ComparisonOperatorSegment, LiteralSegment};
 use crate::core::parser::segments::generator::SegmentGenerator;
@@ -594,15 +593,39 @@ pub fn ansi_dialect() -> Dialect {
         ),
         (
             "QuotedIdentifierSegment".into(),
-            TypedParser::new("double_quote", symbol_factory, None, false, None)
-                .to_matchable()
-                .into(),
+            TypedParser::new(
+                "double_quote",
+                |segment: &dyn Segment| {
+                    SymbolSegment::create(
+                        &segment.get_raw().unwrap(),
+                        &segment.get_position_marker().unwrap(),
+                        SymbolSegmentNewArgs { r#type: "quoted_identifier" },
+                    )
+                },
+                None,
+                false,
+                None,
+            )
+            .to_matchable()
+            .into(),
         ),
         (
             "QuotedLiteralSegment".into(),
-            TypedParser::new("single_quote", symbol_factory, None, false, None)
-                .to_matchable()
-                .into(),
+            TypedParser::new(
+                "single_quote",
+                |segment: &dyn Segment| {
+                    SymbolSegment::create(
+                        &segment.get_raw().unwrap(),
+                        &segment.get_position_marker().unwrap(),
+                        SymbolSegmentNewArgs { r#type: "quoted_literal" },
+                    )
+                },
+                None,
+                false,
+                None,
+            )
+            .to_matchable()
+            .into(),
         ),
         (
             "SingleQuotedIdentifierSegment".into(),
@@ -2524,12 +2547,17 @@ pub struct FileSegment {
 }
 
 impl FileSegment {
+    /// Parse the whole file, recovering at statement delimiters.
+    ///
+    /// Statements which can't be parsed are wrapped in an
+    /// unparsable segment. Errors raised while matching them are returned
+    /// alongside the tree rather than failing the whole file.
     pub fn root_parse(
         &self,
         segments: &[ErasedSegment],
         parse_context: &mut ParseContext,
         _f_name: Option<String>,
-    ) -> Result<ErasedSegment, SQLParseError> {
+    ) -> Result<(ErasedSegment, Vec<SQLParseError>), SQLParseError> {
         // Trim the start
         let start_idx = segments.iter().position(|segment| segment.is_code()).unwrap_or(0);
 
@@ -2547,7 +2575,7 @@ impl FileSegment {
             let b = pos_marker(&*file).into();
             file.get_mut().set_position_marker(b);
 
-            return Ok(file);
+            return Ok((file, Vec::new()));
         }
 
         let final_seg = segments.last().unwrap();
@@ -2555,35 +2583,88 @@ impl FileSegment {
 
         let _closing_position = final_seg.get_position_marker().unwrap().templated_slice.end;
 
-        let match_result = parse_context.progress_bar(|this| {
-            // NOTE: Don't call .match() on the segment class itself, but go
-            // straight to the match grammar inside.
-            self.match_grammar().unwrap().match_segments(&segments[start_idx..end_idx], this)
-        })?;
-
-        let has_match = match_result.has_match();
-        let MatchResult { matched_segments, unmatched_segments } = match_result;
-
-        let content: Vec<_> = if !has_match {
-            vec![UnparsableSegment::new(segments[start_idx..end_idx].to_vec()).to_erased_segment()]
-        } else if !unmatched_segments.is_empty() {
-            let idx = unmatched_segments
-                .iter()
-                .position(|item| item.is_code())
-                .unwrap_or(unmatched_segments.len());
-            let mut result = Vec::new();
-            result.extend(matched_segments.clone());
-            result.extend(unmatched_segments.iter().take(idx).cloned());
-            if idx < unmatched_segments.len() {
-                result.push(
-                    UnparsableSegment::new(unmatched_segments[idx..].to_vec()).to_erased_segment(),
-                );
+        let grammar = self.match_grammar().unwrap();
+        let delimiter = parse_context.dialect().r#ref("DelimiterGrammar");
+
+        let mut content = Vec::new();
+        let mut errors = Vec::new();
+
+        parse_context.progress_bar(|this| {
+            let mut rest = segments[start_idx..end_idx].to_vec();
+
+            // Statements are matched until one fails, at which point we
+            // resynchronise at the next delimiter so that a single unsupported
+            // statement doesn't make the rest of the file unparsable.
+            while let Some(code_idx) = rest.iter().position(|segment| segment.is_code()) {
+                content.extend(rest.drain(..code_idx));
+
+                // A delimiter left over from a statement we recovered from.
+                if let Ok(match_result) = delimiter.match_segments(&rest, this)
+                    && match_result.has_match()
+                {
+                    content.extend(match_result.matched_segments);
+                    rest = match_result.unmatched_segments;
+                    continue;
+                }
+
+                let next_delimiter = Self::next_delimiter(&rest, &*delimiter, this);
+
+                let attempt = |this: &mut ParseContext| match grammar.match_segments(&rest, this) {
+                    Ok(match_result) if match_result.has_match() => Ok(match_result),
+                    // Something later in the file may be what failed, so try
+                    // again with just this statement.
+                    _ => {
+                        this.take_furthest_failure();
+                        grammar.match_segments(&rest[..next_delimiter], this).map(
+                            |mut match_result| {
+                                match_result
+                                    .unmatched_segments
+                                    .extend_from_slice(&rest[next_delimiter..]);
+                                match_result
+                            },
+                        )
+                    }
+                };
+
+                // Failures are only recorded to explain what didn't parse, by
+                // matching it again.
+                let mut match_result = attempt(this);
+                let parsed = match_result.as_ref().is_ok_and(|match_result| {
+                    match_result.has_match()
+                        && match_result.matched_segments.iter().all(|segment| {
+                            segment.recursive_crawl(&["unparsable"], false, None, true).is_empty()
+                        })
+                });
+                if !parsed {
+                    match_result = this.track_failures(attempt);
+                }
+
+                match match_result {
+                    Ok(match_result) if match_result.has_match() => {
+                        content.extend(match_result.matched_segments);
+                        rest = match_result.unmatched_segments;
+                    }
+                    match_result => {
+                        let end = rest[..next_delimiter]
+                            .iter()
+                            .rposition(|segment| segment.is_code())
+                            .map_or(next_delimiter, |idx| idx + 1);
+
+                        content.push(
+                            this.unparsable(rest.drain(..end).collect())
+                                .with_expected("StatementSegment")
+                                .to_erased_segment(),
+                        );
+
+                        if let Err(error) = match_result {
+                            errors.push(error);
+                        }
+                    }
+                }
             }
 
-            result
-        } else {
-            chain(matched_segments, unmatched_segments).collect()
-        };
+            content.append(&mut rest);
+        });
 
         let mut result = Vec::new();
         result.extend_from_slice(&segments[..start_idx]);
@@ -2593,7 +2674,24 @@ impl FileSegment {
         let mut file = Self { segments: result, uuid: Uuid::new_v4(), pos_marker: None };
         file.set_position_marker(pos_marker(&file).into());
 
-        Ok(file.to_erased_segment())
+        Ok((file.to_erased_segment(), errors))
+    }
+
+    /// The index of the next delimiter in `segments`, or their length if
+    /// there isn't one.
+    fn next_delimiter(
+        segments: &[ErasedSegment],
+        delimiter: &dyn Matchable,
+        parse_context: &mut ParseContext,
+    ) -> usize {
+        (0..segments.len())
+            .filter(|&idx| segments[idx].is_code())
+            .find(|&idx| {
+                delimiter
+                    .match_segments(&segments[idx..], parse_context)
+                    .map_or(false, |match_result| match_result.has_match())
+            })
+            .unwrap_or(segments.len())
     }
 }
 
@@ -2733,7 +2831,7 @@ impl NodeTrait for UnorderedSelectStatementSegment {
     }
 
     fn class_types() -> AHashSet<String> {
-        ["select_clause"].map(ToOwned::to_owned).into_iter().collect()
+        ["select_statement"].map(ToOwned::to_owned).into_iter().collect()
     }
 }
 
@@ -2885,6 +2983,10 @@ impl NodeTrait for SetExpressionSegment {
         ])
         .to_matchable()
     }
+
+    fn class_types() -> AHashSet<String> {
+        ["set_expression".into()].into_iter().collect()
+    }
 }
 
 pub struct FromClauseSegment;
@@ -3023,10 +3125,32 @@ impl NodeTrait for SelectClauseElementSegment {
 
 impl Node<SelectClauseElementSegment> {
     pub fn alias(&self) -> Option<ColumnAliasInfo> {
-        let _alias_expression_segment =
-            self.recursive_crawl(&["alias_expression"], true, None, true).first()?.clone();
+        let alias_expression_segment = self.child(&["alias_expression"])?;
+
+        let alias_identifier_segment = alias_expression_segment
+            .segments()
+            .iter()
+            .find(|it| it.is_type("identifier") || it.is_type("naked_identifier"))?
+            .clone();
+
+        let aliased_segment = self
+            .segments
+            .iter()
+            .find(|&it| !it.is_whitespace() && !it.is_meta() && it != &alias_expression_segment)?
+            .clone();
+
+        let column_reference_segments = if aliased_segment.is_type("column_reference") {
+            vec![aliased_segment.clone()]
+        } else {
+            aliased_segment.recursive_crawl(&["column_reference"], true, None, true)
+        };
 
-        unimplemented!()
+        ColumnAliasInfo {
+            alias_identifier_name: alias_identifier_segment.get_raw().unwrap(),
+            aliased_segment,
+            column_reference_segments,
+        }
+        .into()
     }
 }
 
@@ -3132,6 +3256,10 @@ impl NodeTrait for ExpressionSegment {
     fn match_grammar() -> Rc<dyn Matchable> {
         Ref::new("Expression_A_Grammar").to_matchable()
     }
+
+    fn class_types() -> AHashSet<String> {
+        ["expression".into()].into()
+    }
 }
 
 pub struct FromExpressionSegment;
@@ -3170,6 +3298,10 @@ impl NodeTrait for FromExpressionSegment {
         ])])
         .to_matchable()
     }
+
+    fn class_types() -> AHashSet<String> {
+        ["from_expression".into()].into()
+    }
 }
 
 pub struct FromExpressionElementSegment;
@@ -3359,6 +3491,11 @@ impl Node<ObjectReferenceSegment> {
         vec![]
     }
 
+    /// Whether the reference is qualified, e.g. `tbl.col` rather than `col`.
+    pub fn is_qualified(&self) -> bool {
+        self.segments.iter().any(|it| it.is_type("dot"))
+    }
+
     pub fn iter_raw_references(&self) -> Vec<ObjectReferencePart> {
         let mut acc = Vec::new();
 
@@ -1,12 +1,14 @@
 use itertools::{chain, Itertools};
 
-use crate::core::rules::base::{ErasedRule, RuleManifest, RuleSet};
+use crate::core::rules::base::{ErasedRule, RuleSet};
 use crate::helpers::IndexMap;
 
 pub mod aliasing;
 pub mod ambiguous;
 pub mod capitalisation;
 pub mod convention;
+pub mod custom;
+pub mod docs;
 pub mod l001;
 pub mod layout;
 pub mod references;
@@ -31,19 +33,137 @@ pub fn get_ruleset() -> RuleSet {
     let rules = rules();
     register.reserve(rules.len());
 
+    let mut ruleset = RuleSet {
+        _name: "standard".into(),
+        _config_info: <_>::default(),
+        register,
+        warnings: Vec::new(),
+    };
     for rule in rules {
-        register.insert(
-            rule.code(),
-            RuleManifest {
-                code: rule.code(),
-                name: rule.name(),
-                description: rule.description(),
-                groups: rule.groups(),
-                aliases: <_>::default(),
-                rule_class: rule,
-            },
-        );
+        ruleset.register_rule(rule);
     }
+    ruleset
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+    use itertools::Itertools;
+    use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+    use serde::Deserialize;
+
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::linter::linter::Linter;
+    use crate::helpers;
+
+    /// A case of a rule's YAML fixture, in the format of SQLFluff's.
+    #[derive(Debug, Deserialize)]
+    struct TestCase {
+        pass_str: Option<String>,
+        fail_str: Option<String>,
+        fix_str: Option<String>,
+        #[serde(default)]
+        configs: serde_yaml::Mapping,
+        /// Why the case is skipped, if it is.
+        ignored: Option<String>,
+    }
+
+    /// Config sections from YAML, with scalars read as they are in config
+    /// files.
+    fn config_value(value: serde_yaml::Value) -> Value {
+        match value {
+            serde_yaml::Value::Mapping(mapping) => Value::Map(
+                mapping
+                    .into_iter()
+                    .map(|(key, value)| (key.as_str().unwrap().to_owned(), config_value(value)))
+                    .collect(),
+            ),
+            serde_yaml::Value::Null => Value::None,
+            serde_yaml::Value::Bool(value) => Value::Bool(value),
+            serde_yaml::Value::Number(value) => value.to_string().parse().unwrap(),
+            serde_yaml::Value::String(value) => value.parse().unwrap(),
+            value => unimplemented!("config value {value:?}"),
+        }
+    }
+
+    /// The violations of `code` in `sql`, and `sql` once they're fixed.
+    ///
+    /// Both `sql` and the fixed SQL have to parse, as rules are only reliable
+    /// on SQL which does.
+    fn lint_and_fix(config: &FluffConfig, code: &str, sql: &str) -> (usize, String) {
+        let mut linter = Linter::new(config.clone(), None, None);
+        let rules = linter.get_rulepack().rules();
+
+        let mut lint = |sql: &str, fix| {
+            let mut result = linter.lint_string_wrapped(sql.into(), None, Some(fix), rules.clone());
+            let file = result.paths.pop().unwrap().files.pop().unwrap();
+
+            let unparsable =
+                file.violations.iter().filter(|violation| violation.rule_code() == "PRS");
+            let unparsable = unparsable.map(|violation| &violation.description).collect_vec();
+            assert!(unparsable.is_empty(), "{sql:?} doesn't parse: {unparsable:?}");
+
+            file
+        };
 
-    RuleSet { _name: "standard".into(), _config_info: <_>::default(), register }
+        let violations = lint(sql, false)
+            .violations
+            .iter()
+            .filter(|violation| violation.rule_code() == code)
+            .count();
+
+        let fixed = lint(sql, true).fix_string();
+        lint(&fixed, false);
+
+        (violations, fixed)
+    }
+
+    #[test]
+    fn std_rule_cases() {
+        let files =
+            glob::glob("test/fixtures/rules/std_rule_cases/*.yml").unwrap().flatten().collect_vec();
+        assert!(!files.is_empty());
+
+        files.par_iter().for_each(|file| {
+            let _panic = helpers::enter_panic(file.display().to_string());
+
+            let mut cases: serde_yaml::Mapping =
+                serde_yaml::from_str(&std::fs::read_to_string(file).unwrap()).unwrap();
+            let code = cases.remove("rule").unwrap();
+            let code = code.as_str().unwrap();
+
+            for (name, case) in cases {
+                let name = name.as_str().unwrap();
+                let _panic = helpers::enter_panic(name.into());
+                let case: TestCase = serde_yaml::from_value(case).unwrap();
+                if case.ignored.is_some() {
+                    continue;
+                }
+
+                let mut configs: AHashMap<String, Value> = match config_value(case.configs.into()) {
+                    Value::Map(configs) => configs,
+                    _ => unreachable!(),
+                };
+                let core =
+                    configs.entry("core".into()).or_insert_with(|| Value::Map(<_>::default()));
+                core.as_map_mut().unwrap().insert("rules".into(), Value::String(code.into()));
+                let config = FluffConfig::new(configs, None, None);
+
+                match (case.pass_str, case.fail_str) {
+                    (Some(sql), None) => {
+                        let (violations, _) = lint_and_fix(&config, code, &sql);
+                        assert_eq!(violations, 0, "{name}: expected {code} to pass");
+                    }
+                    (None, Some(sql)) => {
+                        let (violations, fixed) = lint_and_fix(&config, code, &sql);
+                        assert_ne!(violations, 0, "{name}: expected {code} to fail");
+                        // Without a `fix_str`, the violations can't be fixed.
+                        let fix_str = case.fix_str.unwrap_or(sql);
+                        pretty_assertions::assert_eq!(fixed, fix_str, "{name}: unexpected fix");
+                    }
+                    _ => panic!("{name}: a case needs either a `pass_str` or a `fail_str`"),
+                }
+            }
+        });
+    }
 }
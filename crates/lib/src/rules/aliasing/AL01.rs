@@ -61,6 +61,10 @@ impl Rule for RuleAL01 {
         "Implicit/explicit aliasing of table."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L011"]
+    }
+
     fn eval(&self, rule_cx: RuleContext) -> Vec<LintResult> {
         let last_seg = rule_cx.parent_stack.last().unwrap();
         let last_seg_ty = last_seg.get_type();
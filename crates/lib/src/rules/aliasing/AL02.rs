@@ -38,6 +38,10 @@ impl Rule for RuleAL02 {
         "Implicit/explicit aliasing of columns."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L012"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         if FunctionalContext::new(context.clone())
             .segment()
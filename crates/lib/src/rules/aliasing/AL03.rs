@@ -35,6 +35,10 @@ impl Rule for RuleAL03 {
         "Column expression without alias. Use explicit `AS` clause."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L013"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let functional_context = FunctionalContext::new(context.clone());
         let segment = functional_context.segment();
@@ -24,6 +24,10 @@ impl Rule for RuleAL04 {
         "Table aliases should be unique within each clause."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L020"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let Some(select_info) =
             get_select_statement_info(&context.segment, context.dialect.into(), true)
@@ -86,6 +86,10 @@ impl Rule for RuleAL06 {
         "Identify aliases in from clause and join conditions"
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L066"]
+    }
+
     fn load_from_config(&self, _config: &ahash::AHashMap<String, Value>) -> ErasedRule {
         RuleAL06::default().erased()
     }
@@ -195,6 +195,10 @@ impl Rule for RuleAL07 {
         "Avoid table aliases in from clauses and join conditions."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L031"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         if !self.force_enable {
             return Vec::new();
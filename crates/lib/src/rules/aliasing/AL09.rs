@@ -0,0 +1,153 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::Segment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::analysis::select::get_select_statement_info;
+use crate::utils::functional::segments::Segments;
+
+/// Column aliases should not alias to itself, i.e. self-alias.
+///
+/// # Anti-pattern
+///
+/// Aliasing the column to itself.
+///
+/// ```sql
+/// SELECT
+///     col AS col
+/// FROM table;
+/// ```
+///
+/// # Best practice
+///
+/// Not to use alias to rename the column to its original name.
+/// Self-aliasing leads to redundant code without changing any functionality.
+///
+/// ```sql
+/// SELECT
+///     col
+/// FROM table;
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleAL09 {}
+
+impl Rule for RuleAL09 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleAL09::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "aliasing.self_alias.column"
+    }
+
+    fn description(&self) -> &'static str {
+        "Column aliases should not alias to itself, i.e. self-alias."
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(select_info) =
+            get_select_statement_info(&context.segment, context.dialect.into(), false)
+        else {
+            return Vec::new();
+        };
+
+        let mut violations = Vec::new();
+
+        for clause_element in &select_info.select_targets {
+            let Some(alias) = clause_element.alias() else { continue };
+
+            // We're only interested in direct aliasing of columns (i.e. not an
+            // expression), and the column must not be qualified.
+            if !alias.aliased_segment.is_type("column_reference")
+                || alias.aliased_segment.segments().len() != 1
+            {
+                continue;
+            }
+
+            if alias.aliased_segment.get_raw().unwrap() != alias.alias_identifier_name {
+                continue;
+            }
+
+            let alias_expression = clause_element.child(&["alias_expression"]).unwrap();
+
+            let mut fixes = vec![LintFix::delete(alias_expression.clone())];
+            fixes.extend(
+                Segments::from_vec(clause_element.segments.clone(), None)
+                    .reversed()
+                    .select(
+                        None,
+                        Some(|it| it.is_whitespace() || it.is_meta()),
+                        Some(&alias_expression),
+                        None,
+                    )
+                    .into_iter()
+                    .map(LintFix::delete),
+            );
+
+            violations.push(LintResult::new(
+                alias.aliased_segment.into(),
+                fixes,
+                None,
+                "Column should not be self-aliased.".to_owned().into(),
+                None,
+            ));
+        }
+
+        violations
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["select_statement"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::aliasing::AL09::RuleAL09;
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleAL09::default().erased()]
+    }
+
+    #[test]
+    fn test_fail_self_alias() {
+        let violations =
+            lint("SELECT col_a AS col_a FROM foo".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+
+        assert_eq!(violations[0].desc(), "Column should not be self-aliased.");
+        assert_eq!(violations[0].line_pos, 8);
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_fix_self_alias() {
+        let fail_str = "SELECT col_a AS col_a, col_b col_b, col_c AS col_d FROM foo";
+        let fix_str = "SELECT col_a, col_b, col_c AS col_d FROM foo";
+
+        assert_eq!(fix(fail_str.into(), rules()), fix_str);
+    }
+
+    #[test]
+    fn test_fix_self_alias_subquery() {
+        let fail_str = "SELECT col_a FROM (SELECT col_a AS col_a FROM foo)";
+        let fix_str = "SELECT col_a FROM (SELECT col_a FROM foo)";
+
+        assert_eq!(fix(fail_str.into(), rules()), fix_str);
+    }
+
+    #[test]
+    fn test_pass_alias_change() {
+        let sql = "SELECT col_a AS col_b, t.col_a AS col_a, col_c AS COL_C, 1 + col_a AS col_a \
+                   FROM foo AS t";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations, []);
+    }
+}
@@ -22,6 +22,10 @@ impl Rule for RuleAM01 {
         "Ambiguous use of 'DISTINCT' in a 'SELECT' statement with 'GROUP BY'."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L021"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let segment = FunctionalContext::new(context.clone()).segment();
 
@@ -0,0 +1,161 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{WhitespaceSegment, WhitespaceSegmentNewArgs};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{CloneRule, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+
+/// `UNION [DISTINCT|ALL]` is preferred over just `UNION`.
+///
+/// # Anti-pattern
+///
+/// In this example, `UNION DISTINCT` should be preferred over `UNION`, because
+/// explicit is better than implicit.
+///
+/// ```sql
+/// SELECT a, b FROM table_1
+/// UNION
+/// SELECT a, b FROM table_2
+/// ```
+///
+/// # Best practice
+///
+/// Specify `DISTINCT` or `ALL` after `UNION` (note that `DISTINCT` is the
+/// default behavior).
+///
+/// ```sql
+/// SELECT a, b FROM table_1
+/// UNION DISTINCT
+/// SELECT a, b FROM table_2
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleAM02;
+
+impl Rule for RuleAM02 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleAM02.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.union"
+    }
+
+    fn description(&self) -> &'static str {
+        "Look for UNION keyword not immediately followed by DISTINCT or ALL."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L033"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let raw = context.segment.get_raw_upper().unwrap();
+
+        if !raw.contains("UNION") || raw.contains("ALL") || raw.contains("DISTINCT") {
+            return Vec::new();
+        }
+
+        let union = context.segment.segments()[0].clone();
+        let distinct = if union.get_raw().unwrap() == "union" { "distinct" } else { "DISTINCT" };
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            vec![LintFix::create_after(
+                union,
+                vec![
+                    WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs),
+                    KeywordSegment::new(distinct.into(), None).to_erased_segment(),
+                ],
+                None,
+            )],
+            None,
+            None,
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["set_operator"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::ambiguous::AM02::RuleAM02;
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleAM02.erased()]
+    }
+
+    #[test]
+    fn test_pass_union_all() {
+        let violations = lint(
+            "SELECT a, b FROM tbl UNION ALL SELECT c, d FROM tbl1".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_union_distinct() {
+        let violations = lint(
+            "SELECT a, b FROM tbl UNION DISTINCT SELECT c, d FROM tbl1".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_bare_union() {
+        let sql = "SELECT a, b FROM tbl UNION SELECT c, d FROM tbl1";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Look for UNION keyword not immediately followed by DISTINCT or ALL."
+        );
+        assert_eq!(violations.len(), 1);
+
+        assert_eq!(
+            fix(sql.into(), rules()),
+            "SELECT a, b FROM tbl UNION DISTINCT SELECT c, d FROM tbl1"
+        );
+    }
+
+    #[test]
+    fn test_fail_bare_union_lowercase() {
+        let sql = "select a, b from tbl union select c, d from tbl1";
+        assert_eq!(
+            fix(sql.into(), rules()),
+            "select a, b from tbl union distinct select c, d from tbl1"
+        );
+    }
+
+    #[test]
+    fn test_fail_triple_join_with_one_bad() {
+        let sql =
+            "SELECT a, b FROM tbl UNION DISTINCT SELECT c, d FROM tbl1 UNION SELECT e, f FROM tbl2";
+        assert_eq!(
+            fix(sql.into(), rules()),
+            "SELECT a, b FROM tbl UNION DISTINCT SELECT c, d FROM tbl1 UNION DISTINCT SELECT e, f \
+             FROM tbl2"
+        );
+    }
+}
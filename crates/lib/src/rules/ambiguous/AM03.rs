@@ -0,0 +1,207 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    ErasedSegment, WhitespaceSegment, WhitespaceSegmentNewArgs,
+};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{CloneRule, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+
+/// Ambiguous ordering directions for columns in order by clause.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// SELECT
+///     a, b
+/// FROM foo
+/// ORDER BY a, b DESC
+/// ```
+///
+/// # Best practice
+///
+/// If any columns in the `ORDER BY` clause specify `ASC` or `DESC`, they should
+/// all do so.
+///
+/// ```sql
+/// SELECT
+///     a, b
+/// FROM foo
+/// ORDER BY a ASC, b DESC
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleAM03;
+
+struct OrderByColumnInfo {
+    separator: ErasedSegment,
+    order: Option<String>,
+}
+
+impl Rule for RuleAM03 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleAM03.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.order_by"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ambiguous ordering directions for columns in order by clause."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L037"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        // Ignore order by clauses belonging to window functions.
+        if context.parent_stack.last().is_some_and(|it| it.is_type("window_specification")) {
+            return Vec::new();
+        }
+
+        let orderby_spec = Self::get_orderby_info(&context.segment);
+
+        // If ALL columns are explicit (ASC/DESC) or implicit, pass.
+        let implicit = orderby_spec.iter().filter(|info| info.order.is_none()).count();
+        if implicit == 0 || implicit == orderby_spec.len() {
+            return Vec::new();
+        }
+
+        let mut fixes = Vec::new();
+        for col_info in orderby_spec {
+            if col_info.order.is_some() {
+                continue;
+            }
+
+            // Since ASC is default in SQL, add in ASC for fix.
+            let edit = vec![
+                WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs),
+                KeywordSegment::new("ASC".into(), None).to_erased_segment(),
+            ];
+
+            fixes.push(if col_info.separator.get_raw().unwrap() == "," {
+                LintFix::create_before(col_info.separator, edit)
+            } else {
+                LintFix::create_after(col_info.separator, edit, None)
+            });
+        }
+
+        vec![LintResult::new(
+            context.segment.into(),
+            fixes,
+            None,
+            "Ambiguous order by clause. Order by clauses should specify order direction for ALL \
+             columns or NO columns."
+                .to_owned()
+                .into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["orderby_clause"].into()).into()
+    }
+}
+
+impl RuleAM03 {
+    fn get_orderby_info(segment: &ErasedSegment) -> Vec<OrderByColumnInfo> {
+        let mut result = Vec::new();
+        let mut found_column_reference = false;
+        let mut ordering_reference = None;
+        let mut last_code = None;
+
+        for child_segment in segment.segments() {
+            if child_segment.is_type("column_reference") {
+                found_column_reference = true;
+            } else if child_segment.is_type("keyword")
+                && matches!(child_segment.get_raw_upper().unwrap().as_str(), "ASC" | "DESC")
+            {
+                ordering_reference = child_segment.get_raw_upper();
+            }
+
+            if found_column_reference && child_segment.get_raw().unwrap() == "," {
+                result.push(OrderByColumnInfo {
+                    separator: child_segment.clone(),
+                    order: ordering_reference.take(),
+                });
+                found_column_reference = false;
+            }
+
+            if child_segment.is_code() {
+                last_code = Some(child_segment.clone());
+            }
+        }
+
+        // Reached end of clause.
+        if found_column_reference && let Some(last_code) = last_code {
+            result.push(OrderByColumnInfo { separator: last_code, order: ordering_reference });
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::ambiguous::AM03::RuleAM03;
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleAM03.erased()]
+    }
+
+    #[test]
+    fn test_unspecified() {
+        let violations =
+            lint("SELECT * FROM t ORDER BY a".into(), "ansi".into(), rules(), None, None).unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_all_specified() {
+        let violations = lint(
+            "SELECT * FROM t ORDER BY a ASC, b DESC".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_unspecified_unspecified() {
+        let violations =
+            lint("SELECT * FROM t ORDER BY a, b".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_unspecified_desc() {
+        let sql = "SELECT * FROM t ORDER BY a, b DESC";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Ambiguous order by clause. Order by clauses should specify order direction for ALL \
+             columns or NO columns."
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules()), "SELECT * FROM t ORDER BY a ASC, b DESC");
+    }
+
+    #[test]
+    fn test_desc_unspecified() {
+        let sql = "SELECT * FROM t ORDER BY a DESC, b";
+        assert_eq!(fix(sql.into(), rules()), "SELECT * FROM t ORDER BY a DESC, b ASC");
+    }
+}
@@ -0,0 +1,222 @@
+use std::cell::RefCell;
+
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::dialects::common::AliasInfo;
+use crate::core::rules::base::{CloneRule, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::analysis::query::{Query, Source};
+use crate::utils::functional::context::FunctionalContext;
+
+const START_TYPES: [&str; 3] = ["select_statement", "set_expression", "with_compound_statement"];
+
+/// Query produces an unknown number of result columns.
+///
+/// # Anti-pattern
+///
+/// Querying all columns using `*` produces a query result where the number
+/// or ordering of columns changes if the upstream table's schema changes.
+/// This should generally be avoided because it can cause slow performance,
+/// cause important schema changes to go undetected, or break production code.
+/// For example:
+///
+/// * If a query does `SELECT t.*` and is expected to return columns `a`, `b`,
+///   and `c`, the actual columns returned will be wrong/different if columns
+///   are added to or deleted from the input table.
+/// * `UNION` and `DIFFERENCE` clauses require the inputs have the same number
+///   of columns (and compatible types).
+/// * `JOIN` queries may break due to new column name conflicts, e.g. the query
+///   references a column `c` which initially existed in only one input table
+///   but a column of the same name is added to another table.
+/// * `CREATE TABLE (<<column schema>>) AS SELECT *`
+///
+/// ```sql
+/// WITH cte AS (
+///     SELECT * FROM foo
+/// )
+///
+/// SELECT * FROM cte
+/// UNION
+/// SELECT a, b FROM t
+/// ```
+///
+/// # Best practice
+///
+/// Somewhere along the "path" to the source data, specify columns explicitly.
+///
+/// ```sql
+/// WITH cte AS (
+///     SELECT * FROM foo
+/// )
+///
+/// SELECT a, b FROM cte
+/// UNION
+/// SELECT a, b FROM t
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleAM04;
+
+/// Raised when the number of result columns of a query cannot be determined.
+struct UnknownColumns;
+
+impl Rule for RuleAM04 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleAM04.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.column_count"
+    }
+
+    fn description(&self) -> &'static str {
+        "Query produces an unknown number of result columns."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L044"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        // Only check the outermost query.
+        if FunctionalContext::new(context.clone())
+            .parent_stack()
+            .any(Some(|it| START_TYPES.iter().any(|ty| it.is_type(ty))))
+        {
+            return Vec::new();
+        }
+
+        let query: Query<'_, ()> = Query::from_segment(&context.segment, context.dialect, None);
+        if Self::analyze_result_columns(query).is_err() {
+            return vec![LintResult::new(context.segment.into(), Vec::new(), None, None, None)];
+        }
+
+        Vec::new()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(START_TYPES.into()).into()
+    }
+}
+
+impl RuleAM04 {
+    fn handle_alias(alias_info: &AliasInfo, query: &Query<'_, ()>) -> Result<(), UnknownColumns> {
+        let select_info_target =
+            query.crawl_sources(&alias_info.from_expression_element, true, true).into_iter().next();
+
+        match select_info_target {
+            Some(Source::Query(query)) => Self::analyze_result_columns(query),
+            // It's an alias to an external table whose number of columns
+            // could vary without our knowledge.
+            Some(Source::External(_)) | None => Err(UnknownColumns),
+        }
+    }
+
+    /// Given info on a list of queries, determine whether they produce a
+    /// known number of result columns.
+    fn analyze_result_columns(query: Query<'_, ()>) -> Result<(), UnknownColumns> {
+        let selectables = RefCell::borrow(&query.inner).selectables.clone();
+
+        for selectable in &selectables {
+            for wildcard in selectable.get_wildcard_info() {
+                if wildcard.tables.is_empty() {
+                    // No table was specified with the wildcard. Assume we're
+                    // querying from a nested select in FROM.
+                    let source = query
+                        .crawl_sources(&selectable.selectable, true, true)
+                        .into_iter()
+                        .find_map(|source| match source {
+                            Source::Query(query) => Some(query),
+                            Source::External(_) => None,
+                        });
+
+                    match source {
+                        Some(source) => Self::analyze_result_columns(source)?,
+                        None => return Err(UnknownColumns),
+                    }
+
+                    continue;
+                }
+
+                for wildcard_table in &wildcard.tables {
+                    if let Some(alias_info) = selectable.find_alias(wildcard_table) {
+                        Self::handle_alias(&alias_info, &query)?;
+                    } else if let Some(cte) = query.lookup_cte(wildcard_table, true) {
+                        Self::analyze_result_columns(cte)?;
+                    } else {
+                        // Not an alias or a CTE, must be an external table.
+                        return Err(UnknownColumns);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::ambiguous::AM04::RuleAM04;
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleAM04.erased()]
+    }
+
+    fn violations(sql: &str) -> usize {
+        lint(sql.into(), "ansi".into(), rules(), None, None).unwrap().len()
+    }
+
+    #[test]
+    fn test_pass_known_number_of_result_columns() {
+        assert_eq!(violations("select a, b from t"), 0);
+        assert_eq!(violations("select a from t union select b from u"), 0);
+    }
+
+    #[test]
+    fn test_fail_unknown_number_of_result_columns() {
+        let violations =
+            lint("select * from t".into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Query produces an unknown number of result columns.");
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_fail_qualified_wildcard() {
+        assert_eq!(violations("select t.* from t"), 1);
+        assert_eq!(violations("select a.* from t as a"), 1);
+    }
+
+    #[test]
+    fn test_pass_wildcard_from_cte() {
+        assert_eq!(violations("with cte as (select a, b from t) select * from cte"), 0);
+        assert_eq!(violations("with cte as (select a, b from t) select cte.* from cte"), 0);
+    }
+
+    #[test]
+    fn test_fail_wildcard_from_cte() {
+        assert_eq!(violations("with cte as (select * from t) select * from cte"), 1);
+    }
+
+    #[test]
+    fn test_pass_wildcard_from_subquery() {
+        assert_eq!(violations("select * from (select a, b from t)"), 0);
+        assert_eq!(violations("select x.* from (select a, b from t) as x"), 0);
+    }
+
+    #[test]
+    fn test_fail_wildcard_from_subquery() {
+        assert_eq!(violations("select * from (select * from t)"), 1);
+    }
+
+    #[test]
+    fn test_fail_set_expression() {
+        assert_eq!(violations("select a from t union select * from u"), 1);
+    }
+}
@@ -0,0 +1,232 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{WhitespaceSegment, WhitespaceSegmentNewArgs};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinTypes {
+    Inner,
+    Outer,
+    Both,
+}
+
+/// Join clauses should be fully qualified.
+///
+/// By default this rule is configured to enforce fully qualified `INNER JOIN`
+/// clauses, but not `[LEFT/RIGHT/FULL] OUTER JOIN`. If you prefer a stricter
+/// lint then this is configurable.
+///
+/// # Anti-pattern
+///
+/// A join is used without specifying the kind of join.
+///
+/// ```sql
+/// SELECT
+///     foo
+/// FROM bar
+/// JOIN baz;
+/// ```
+///
+/// # Best practice
+///
+/// Use `INNER JOIN` rather than `JOIN`.
+///
+/// ```sql
+/// SELECT
+///     foo
+/// FROM bar
+/// INNER JOIN baz;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleAM05 {
+    fully_qualify_join_types: JoinTypes,
+}
+
+impl Default for RuleAM05 {
+    fn default() -> Self {
+        Self { fully_qualify_join_types: JoinTypes::Inner }
+    }
+}
+
+impl Rule for RuleAM05 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let fully_qualify_join_types =
+            match config.get("fully_qualify_join_types").unwrap().as_string().unwrap() {
+                "inner" => JoinTypes::Inner,
+                "outer" => JoinTypes::Outer,
+                "both" => JoinTypes::Both,
+                _ => unreachable!(),
+            };
+
+        RuleAM05 { fully_qualify_join_types }.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.join"
+    }
+
+    fn description(&self) -> &'static str {
+        "Join clauses should be fully qualified."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L051"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let join_clause_keywords = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|segment| segment.is_type("keyword"))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let Some(first_keyword) = join_clause_keywords.first() else {
+            return Vec::new();
+        };
+
+        let first_raw_upper = first_keyword.get_raw_upper().unwrap();
+
+        // Identify LEFT/RIGHT/FULL JOIN and if the next keyword is JOIN.
+        if matches!(self.fully_qualify_join_types, JoinTypes::Outer | JoinTypes::Both)
+            && matches!(first_raw_upper.as_str(), "RIGHT" | "LEFT" | "FULL")
+            && join_clause_keywords.get(1).is_some_and(|it| it.get_raw_upper().unwrap() == "JOIN")
+        {
+            // Define basic-level OUTER capitalization based on JOIN.
+            let outer_kw = if join_clause_keywords[1].get_raw().unwrap() == "join" {
+                "outer"
+            } else {
+                "OUTER"
+            };
+
+            return vec![LintResult::new(
+                first_keyword.clone().into(),
+                vec![LintFix::create_after(
+                    first_keyword.clone(),
+                    vec![
+                        WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs),
+                        KeywordSegment::new(outer_kw.into(), None).to_erased_segment(),
+                    ],
+                    None,
+                )],
+                None,
+                None,
+                None,
+            )];
+        }
+
+        // Identify lone JOIN by looking at first child segment.
+        if matches!(self.fully_qualify_join_types, JoinTypes::Inner | JoinTypes::Both)
+            && first_raw_upper == "JOIN"
+        {
+            // Define basic-level INNER capitalization based on JOIN.
+            let inner_kw =
+                if first_keyword.get_raw().unwrap() == "join" { "inner" } else { "INNER" };
+
+            return vec![LintResult::new(
+                first_keyword.clone().into(),
+                vec![LintFix::create_before(
+                    first_keyword.clone(),
+                    vec![
+                        KeywordSegment::new(inner_kw.into(), None).to_erased_segment(),
+                        WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs),
+                    ],
+                )],
+                None,
+                None,
+                None,
+            )];
+        }
+
+        Vec::new()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["join_clause"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::JoinTypes;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::ambiguous::AM05::RuleAM05;
+
+    fn rules(fully_qualify_join_types: JoinTypes) -> Vec<ErasedRule> {
+        vec![RuleAM05 { fully_qualify_join_types }.erased()]
+    }
+
+    #[test]
+    fn test_fail_lone_join() {
+        let sql = "SELECT foo.a, bar.b FROM foo JOIN bar;\n";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(JoinTypes::Inner), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Join clauses should be fully qualified.");
+        assert_eq!(violations.len(), 1);
+
+        assert_eq!(
+            fix(sql.into(), rules(JoinTypes::Inner)),
+            "SELECT foo.a, bar.b FROM foo INNER JOIN bar;\n"
+        );
+    }
+
+    #[test]
+    fn test_fail_lone_join_lowercase() {
+        assert_eq!(
+            fix("select foo.a, bar.b from foo join bar;\n".into(), rules(JoinTypes::Inner)),
+            "select foo.a, bar.b from foo inner join bar;\n"
+        );
+    }
+
+    #[test]
+    fn test_pass_inner_join() {
+        let sql = "SELECT foo.a, bar.b FROM foo INNER JOIN bar;\n";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(JoinTypes::Both), None, None).unwrap();
+
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_left_join_default() {
+        let sql = "SELECT foo.a, bar.b FROM foo LEFT JOIN bar;\n";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(JoinTypes::Inner), None, None).unwrap();
+
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_left_join_outer() {
+        let sql = "SELECT foo.a, bar.b FROM foo left join bar;\n";
+
+        assert_eq!(
+            fix(sql.into(), rules(JoinTypes::Outer)),
+            "SELECT foo.a, bar.b FROM foo left outer join bar;\n"
+        );
+        assert_eq!(
+            fix("SELECT foo.a FROM foo JOIN bar;\n".into(), rules(JoinTypes::Outer)),
+            "SELECT foo.a FROM foo JOIN bar;\n"
+        );
+    }
+
+    #[test]
+    fn test_fail_both() {
+        let sql = "SELECT foo.a FROM foo FULL JOIN bar JOIN baz;\n";
+
+        assert_eq!(
+            fix(sql.into(), rules(JoinTypes::Both)),
+            "SELECT foo.a FROM foo FULL OUTER JOIN bar INNER JOIN baz;\n"
+        );
+    }
+}
@@ -20,8 +20,11 @@ impl Default for RuleAM06 {
 }
 
 impl Rule for RuleAM06 {
-    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
-        RuleAM06 { group_by_and_order_by_style: "consistent".into() }.erased()
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let group_by_and_order_by_style =
+            config.get("group_by_and_order_by_style").unwrap().as_string().unwrap().into();
+
+        RuleAM06 { group_by_and_order_by_style }.erased()
     }
 
     fn name(&self) -> &'static str {
@@ -32,6 +35,10 @@ impl Rule for RuleAM06 {
         "Inconsistent column references in 'GROUP BY/ORDER BY' clauses."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L054"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let skip = FunctionalContext::new(context.clone()).parent_stack().any(Some(|it| {
             let ignore_types = ["withingroup_clause", "window_specification", "aggregate_order_by"];
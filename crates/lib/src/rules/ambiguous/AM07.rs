@@ -0,0 +1,283 @@
+use std::cell::RefCell;
+
+use ahash::{AHashMap, AHashSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{CloneRule, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::analysis::query::{Query, Selectable, Source, WildcardInfo};
+
+/// Queries within set query produce different numbers of columns.
+///
+/// # Anti-pattern
+///
+/// When writing set expressions, all queries must return the same number of
+/// columns.
+///
+/// ```sql
+/// WITH cte AS (
+///     SELECT
+///         a,
+///         b
+///     FROM foo
+/// )
+/// SELECT * FROM cte
+/// UNION
+/// SELECT
+///     c,
+///     d,
+///     e
+///  FROM t
+/// ```
+///
+/// # Best practice
+///
+/// Always specify columns when writing set queries and ensure that they all
+/// select the same number of columns.
+///
+/// ```sql
+/// WITH cte AS (
+///     SELECT a, b FROM foo
+/// )
+/// SELECT
+///     a,
+///     b
+/// FROM cte
+/// UNION
+/// SELECT
+///     c,
+///     d
+/// FROM t
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleAM07;
+
+impl Rule for RuleAM07 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleAM07.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.set_columns"
+    }
+
+    fn description(&self) -> &'static str {
+        "Queries within set query produce different numbers of columns."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L068"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        // Is the parent of the set expression a WITH expression? If it is,
+        // work from there instead.
+        let root = context
+            .parent_stack
+            .iter()
+            .rev()
+            .find(|parent| parent.is_type("with_compound_statement"))
+            .unwrap_or(&context.segment);
+
+        let query: Query<'_, ()> = Query::from_segment(root, context.dialect, None);
+        let (set_segment_select_sizes, resolve_wildcard) = Self::get_select_target_counts(&query);
+
+        // If queries had different select target counts and all wildcards
+        // have been resolved, fail.
+        if set_segment_select_sizes.len() > 1 && resolve_wildcard {
+            return vec![LintResult::new(context.segment.into(), Vec::new(), None, None, None)];
+        }
+
+        Vec::new()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["set_expression"].into()).into()
+    }
+}
+
+impl RuleAM07 {
+    /// Attempt to resolve a full query which may contain wildcards.
+    ///
+    /// If one of the source queries for a query within the set is itself a
+    /// set expression, just use the first query. If that first query isn't
+    /// reflective of the others, that will be caught when that segment is
+    /// processed.
+    fn resolve_wild_query(query: &Query<'_, ()>) -> (usize, bool) {
+        let selectable = RefCell::borrow(&query.inner).selectables.first().cloned();
+
+        match selectable {
+            Some(selectable) => Self::resolve_selectable(&selectable, query),
+            None => (0, false),
+        }
+    }
+
+    /// Attempt to resolve a single wildcard to a number of columns.
+    fn resolve_selectable_wildcard(
+        wildcard: &WildcardInfo,
+        selectable: &Selectable,
+        root_query: &Query<'_, ()>,
+    ) -> (usize, bool) {
+        // If there is no table specified, it is likely a subquery. Crawl the
+        // query looking for the subquery, probably in the FROM.
+        if wildcard.tables.is_empty() {
+            for source in root_query.crawl_sources(&selectable.selectable, false, true) {
+                if let Source::Query(query) = source {
+                    return Self::resolve_wild_query(&query);
+                }
+            }
+
+            return (0, false);
+        }
+
+        // There might be multiple tables referenced in some wildcard cases.
+        let mut num_cols = 0;
+        let mut resolved = true;
+
+        for wildcard_table in &wildcard.tables {
+            let mut cte_name = wildcard_table.clone();
+
+            // Get the AliasInfo for the table referenced in the wildcard
+            // expression, and crawl inside the FROM expression looking for
+            // something to resolve to.
+            if let Some(alias_info) = selectable.find_alias(wildcard_table) {
+                let select_info_target = root_query
+                    .crawl_sources(&alias_info.from_expression_element, false, true)
+                    .into_iter()
+                    .next();
+
+                match select_info_target {
+                    Some(Source::External(name)) => cte_name = name,
+                    Some(Source::Query(query)) => {
+                        let (cols, query_resolved) = Self::resolve_wild_query(&query);
+                        num_cols += cols;
+                        resolved = resolved && query_resolved;
+                        continue;
+                    }
+                    None => {}
+                }
+            }
+
+            match root_query.lookup_cte(&cte_name, true) {
+                Some(cte) => {
+                    let (cols, cte_resolved) = Self::resolve_wild_query(&cte);
+                    num_cols += cols;
+                    resolved = resolved && cte_resolved;
+                }
+                None => resolved = false,
+            }
+        }
+
+        (num_cols, resolved)
+    }
+
+    /// Resolve the number of columns in a single selectable.
+    fn resolve_selectable(selectable: &Selectable, root_query: &Query<'_, ()>) -> (usize, bool) {
+        let wildcard_info = selectable.get_wildcard_info();
+        let select_targets =
+            selectable.select_info().map_or(0, |select_info| select_info.select_targets.len());
+
+        // Start with the number of non-wild columns.
+        let mut num_cols = select_targets - wildcard_info.len();
+        let mut resolved = true;
+
+        // If the set query contains one or more wildcards, attempt to resolve
+        // it to a list of select targets that can be counted.
+        for wildcard in &wildcard_info {
+            let (cols, wildcard_resolved) =
+                Self::resolve_selectable_wildcard(wildcard, selectable, root_query);
+            resolved = resolved && wildcard_resolved;
+            num_cols += cols;
+        }
+
+        (num_cols, resolved)
+    }
+
+    /// Given a set expression, get the number of select targets in each
+    /// query.
+    fn get_select_target_counts(query: &Query<'_, ()>) -> (AHashSet<usize>, bool) {
+        let mut select_target_counts = AHashSet::new();
+        let mut resolved_wildcard = true;
+
+        let selectables = RefCell::borrow(&query.inner).selectables.clone();
+        for selectable in &selectables {
+            let (cnt, res) = Self::resolve_selectable(selectable, query);
+            if !res {
+                resolved_wildcard = false;
+            }
+            select_target_counts.insert(cnt);
+        }
+
+        (select_target_counts, resolved_wildcard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::ambiguous::AM07::RuleAM07;
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleAM07.erased()]
+    }
+
+    fn violations(sql: &str) -> usize {
+        lint(sql.into(), "ansi".into(), rules(), None, None).unwrap().len()
+    }
+
+    #[test]
+    fn test_pass_known_number_of_result_columns() {
+        assert_eq!(violations("select a, b from t union all select c, d from k"), 0);
+    }
+
+    #[test]
+    fn test_fail_known_number_of_result_columns() {
+        let violations = lint(
+            "select a from t union all select c, d from k".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Queries within set query produce different numbers of columns."
+        );
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_pass_unknown_wildcard_number_of_result_columns() {
+        assert_eq!(violations("select a from t1 union all select * from t2"), 0);
+    }
+
+    #[test]
+    fn test_pass_known_number_of_result_columns_cte() {
+        let sql = "with cte as (select a, b from t) select * from cte union select c, d from t2";
+        assert_eq!(violations(sql), 0);
+    }
+
+    #[test]
+    fn test_fail_known_number_of_result_columns_cte() {
+        let sql = "with cte as (select a, b, c from t) select * from cte union select c, d from t2";
+        assert_eq!(violations(sql), 1);
+    }
+
+    #[test]
+    fn test_fail_qualified_wildcard_from_subquery() {
+        let sql = "select x.* from (select a, b, c from t) as x union select c, d from t2";
+        assert_eq!(violations(sql), 1);
+    }
+
+    #[test]
+    fn test_pass_wildcard_from_subquery() {
+        let sql = "select * from (select a, b from t) union select c, d from t2";
+        assert_eq!(violations(sql), 0);
+    }
+}
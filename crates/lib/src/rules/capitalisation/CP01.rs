@@ -49,6 +49,10 @@ impl Rule for RuleCP01 {
         "Inconsistent capitalisation of keywords."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L010"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let parent = context.parent_stack.last().unwrap();
 
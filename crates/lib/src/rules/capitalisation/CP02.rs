@@ -38,6 +38,10 @@ impl Rule for RuleCP02 {
         "Inconsistent capitalisation of unquoted identifiers."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L014"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         if identifiers_policy_applicable(&self.unquoted_identifiers_policy, &context.parent_stack) {
             self.base.eval(context)
@@ -36,6 +36,10 @@ impl Rule for RuleCP03 {
         "Inconsistent capitalisation of function names."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L030"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         self.base.eval(context)
     }
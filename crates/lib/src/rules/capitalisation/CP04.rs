@@ -36,6 +36,10 @@ impl Rule for RuleCP04 {
         "Inconsistent capitalisation of boolean/null literal."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L040"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         self.base.eval(context)
     }
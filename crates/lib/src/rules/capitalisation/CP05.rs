@@ -30,6 +30,10 @@ impl Rule for RuleCP05 {
         "Inconsistent capitalisation of datatypes."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L063"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
 
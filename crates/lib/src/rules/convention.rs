@@ -1,9 +1,31 @@
 use crate::core::rules::base::ErasedRule;
 
+pub mod CV01;
 pub mod CV02;
+pub mod CV03;
+pub mod CV04;
+pub mod CV05;
+pub mod CV06;
+pub mod CV07;
+pub mod CV08;
+pub mod CV09;
+pub mod CV10;
+pub mod CV11;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
 
-    vec![CV02::RuleCv02::default().erased()]
+    vec![
+        CV01::RuleCV01::default().erased(),
+        CV02::RuleCV02::default().erased(),
+        CV03::RuleCV03::default().erased(),
+        CV04::RuleCV04::default().erased(),
+        CV05::RuleCV05::default().erased(),
+        CV06::RuleCV06::default().erased(),
+        CV07::RuleCV07::default().erased(),
+        CV08::RuleCV08::default().erased(),
+        CV09::RuleCV09::default().erased(),
+        CV10::RuleCV10::default().erased(),
+        CV11::RuleCV11::default().erased(),
+    ]
 }
@@ -0,0 +1,201 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PreferredNotEqualStyle {
+    Consistent,
+    CStyle,
+    Ansi,
+}
+
+struct PriorNotEqualStyle(PreferredNotEqualStyle);
+
+/// Consistent usage of `!=` or `<>` for "not equal to" operator.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// SELECT * FROM X WHERE 1 <> 2 AND 3 != 4;
+/// ```
+///
+/// # Best practice
+///
+/// Ensure all "not equal to" comparisons are consistent, not mixing `!=` and
+/// `<>`.
+///
+/// ```sql
+/// SELECT * FROM X WHERE 1 != 2 AND 3 != 4;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleCV01 {
+    preferred_not_equal_style: PreferredNotEqualStyle,
+}
+
+impl Default for RuleCV01 {
+    fn default() -> Self {
+        Self { preferred_not_equal_style: PreferredNotEqualStyle::Consistent }
+    }
+}
+
+impl Rule for RuleCV01 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let preferred_not_equal_style =
+            match config.get("preferred_not_equal_style").unwrap().as_string().unwrap() {
+                "consistent" => PreferredNotEqualStyle::Consistent,
+                "c_style" => PreferredNotEqualStyle::CStyle,
+                "ansi" => PreferredNotEqualStyle::Ansi,
+                _ => unreachable!(),
+            };
+
+        RuleCV01 { preferred_not_equal_style }.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.not_equal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent usage of ``!=`` or ``<>`` for \"not equal to\" operator."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L061"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        // Get the comparison operator children.
+        let raw_comparison_operators: Vec<_> =
+            context.segment.segments().iter().filter(|it| it.is_code()).cloned().collect();
+
+        // Only check ``<>`` or ``!=`` operators.
+        let [first, second] = raw_comparison_operators.as_slice() else {
+            return Vec::new();
+        };
+
+        let current_style =
+            match (first.get_raw().unwrap().as_str(), second.get_raw().unwrap().as_str()) {
+                ("!", "=") => PreferredNotEqualStyle::CStyle,
+                ("<", ">") => PreferredNotEqualStyle::Ansi,
+                _ => return Vec::new(),
+            };
+
+        // If style is set to "consistent", the first occurrence sets the style.
+        let preferred_style =
+            if self.preferred_not_equal_style == PreferredNotEqualStyle::Consistent {
+                let prior_style =
+                    context.memory.borrow().get::<PriorNotEqualStyle>().map(|prior| prior.0);
+
+                match prior_style {
+                    Some(prior_style) => prior_style,
+                    None => {
+                        context.memory.borrow_mut().insert(PriorNotEqualStyle(current_style));
+                        return Vec::new();
+                    }
+                }
+            } else {
+                self.preferred_not_equal_style
+            };
+
+        if current_style == preferred_style {
+            return Vec::new();
+        }
+
+        let (replacement, first_raw, second_raw) = match preferred_style {
+            PreferredNotEqualStyle::CStyle => ("!=", "!", "="),
+            PreferredNotEqualStyle::Ansi => ("<>", "<", ">"),
+            PreferredNotEqualStyle::Consistent => unreachable!(),
+        };
+
+        let fixes = [(first, first_raw), (second, second_raw)]
+            .into_iter()
+            .map(|(segment, raw)| {
+                LintFix::replace(
+                    segment.clone(),
+                    vec![SymbolSegment::create(
+                        raw,
+                        &<_>::default(),
+                        SymbolSegmentNewArgs { r#type: "raw_comparison_operator" },
+                    )],
+                    None,
+                )
+            })
+            .collect();
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            fixes,
+            None,
+            format!("Use '{replacement}' instead of '{}'.", context.segment.get_raw().unwrap())
+                .into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["not_equal_to"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{PreferredNotEqualStyle, RuleCV01};
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(preferred_not_equal_style: PreferredNotEqualStyle) -> Vec<ErasedRule> {
+        vec![RuleCV01 { preferred_not_equal_style }.erased()]
+    }
+
+    #[test]
+    fn test_pass_consistent() {
+        let violations = lint(
+            "SELECT * FROM X WHERE 1 != 2 AND 3 != 4".into(),
+            "ansi".into(),
+            rules(PreferredNotEqualStyle::Consistent),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_consistent() {
+        let sql = "SELECT * FROM X WHERE 1 <> 2 AND 3 != 4";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(PreferredNotEqualStyle::Consistent), None, None)
+                .unwrap();
+
+        assert_eq!(violations[0].desc(), "Use '<>' instead of '!='.");
+        assert_eq!(violations.len(), 1);
+
+        assert_eq!(
+            fix(sql.into(), rules(PreferredNotEqualStyle::Consistent)),
+            "SELECT * FROM X WHERE 1 <> 2 AND 3 <> 4"
+        );
+    }
+
+    #[test]
+    fn test_fail_c_style() {
+        assert_eq!(
+            fix("SELECT * FROM X WHERE 1 <> 2".into(), rules(PreferredNotEqualStyle::CStyle)),
+            "SELECT * FROM X WHERE 1 != 2"
+        );
+    }
+
+    #[test]
+    fn test_fail_ansi() {
+        assert_eq!(
+            fix("SELECT * FROM X WHERE 1 != 2".into(), rules(PreferredNotEqualStyle::Ansi)),
+            "SELECT * FROM X WHERE 1 <> 2"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// Trailing commas within select clause.
+///
+/// For some database backends this is allowed. For some users this may be a
+/// useful style choice, as it makes adding and removing columns from a query
+/// easier. By default this rule forbids trailing commas; it can be configured
+/// to require them instead.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// SELECT
+///     a,
+///     b,
+/// FROM foo
+/// ```
+///
+/// # Best practice
+///
+/// ```sql
+/// SELECT
+///     a,
+///     b
+/// FROM foo
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleCV03 {
+    select_clause_trailing_comma: String,
+}
+
+impl Default for RuleCV03 {
+    fn default() -> Self {
+        Self { select_clause_trailing_comma: "forbid".into() }
+    }
+}
+
+impl Rule for RuleCV03 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleCV03 {
+            select_clause_trailing_comma: config
+                .get("select_clause_trailing_comma")
+                .unwrap()
+                .as_string()
+                .unwrap()
+                .to_owned(),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.select_trailing_comma"
+    }
+
+    fn description(&self) -> &'static str {
+        "Trailing commas within select clause."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L038"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(last_content) =
+            context.segment.segments().iter().rev().find(|it| it.is_code()).cloned()
+        else {
+            return Vec::new();
+        };
+
+        if self.select_clause_trailing_comma == "forbid" {
+            // Is it a comma?
+            if last_content.is_type("comma") {
+                return vec![LintResult::new(
+                    last_content.clone().into(),
+                    vec![LintFix::delete(last_content)],
+                    None,
+                    "Trailing comma in select statement forbidden".to_owned().into(),
+                    None,
+                )];
+            }
+        } else if self.select_clause_trailing_comma == "require" && !last_content.is_type("comma") {
+            let new_comma = SymbolSegment::create(
+                ",",
+                &<_>::default(),
+                SymbolSegmentNewArgs { r#type: "comma" },
+            );
+
+            return vec![LintResult::new(
+                last_content.clone().into(),
+                vec![LintFix::create_after(last_content, vec![new_comma], None)],
+                None,
+                "Trailing comma in select statement required".to_owned().into(),
+                None,
+            )];
+        }
+
+        Vec::new()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["select_clause"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV03;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(select_clause_trailing_comma: &str) -> Vec<ErasedRule> {
+        vec![
+            RuleCV03 { select_clause_trailing_comma: select_clause_trailing_comma.into() }.erased(),
+        ]
+    }
+
+    #[test]
+    fn test_require_pass() {
+        let violations =
+            lint("SELECT a, b, FROM t".into(), "ansi".into(), rules("require"), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_require_fail() {
+        let sql = "SELECT a, b FROM t";
+        let violations = lint(sql.into(), "ansi".into(), rules("require"), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Trailing comma in select statement required");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules("require")), "SELECT a, b, FROM t");
+    }
+
+    #[test]
+    fn test_forbid_pass() {
+        let violations =
+            lint("SELECT a, b FROM t".into(), "ansi".into(), rules("forbid"), None, None).unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_forbid_fail() {
+        let sql = "SELECT a, b, FROM t";
+        let violations = lint(sql.into(), "ansi".into(), rules("forbid"), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Trailing comma in select statement forbidden");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules("forbid")), "SELECT a, b FROM t");
+    }
+}
@@ -0,0 +1,242 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{ErasedSegment, SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// Use consistent syntax to express "count number of rows".
+///
+/// Note:
+///     If both `prefer_count_1` and `prefer_count_0` are set to true
+///     then `prefer_count_1` has precedence.
+///
+/// `COUNT(*)`, `COUNT(1)`, and even `COUNT(0)` are equivalent syntaxes
+/// in many SQL engines due to optimizers interpreting these instructions as
+/// "count number of rows in result".
+///
+/// The ANSI-92 spec mentions the `COUNT(*)` syntax specifically as
+/// having a special meaning:
+///
+/// > If COUNT(*) is specified, then
+/// > the result is the cardinality of T.
+///
+/// So by default, sqruff enforces the consistent use of `COUNT(*)`.
+///
+/// If the SQL engine you work with, or your team, prefers `COUNT(1)` or
+/// `COUNT(0)` over `COUNT(*)`, you can configure this rule to consistently
+/// enforce your preference.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// select
+///     count(1)
+/// from table_a
+/// ```
+///
+/// # Best practice
+///
+/// Use `count(*)` unless specified otherwise by config `prefer_count_1`,
+/// or `prefer_count_0` as preferred.
+///
+/// ```sql
+/// select
+///     count(*)
+/// from table_a
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleCV04 {
+    prefer_count_1: bool,
+    prefer_count_0: bool,
+}
+
+impl Rule for RuleCV04 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleCV04 {
+            prefer_count_1: config.get("prefer_count_1").unwrap().as_bool().unwrap(),
+            prefer_count_0: config.get("prefer_count_0").unwrap().as_bool().unwrap(),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.count_rows"
+    }
+
+    fn description(&self) -> &'static str {
+        "Use consistent syntax to express \"count number of rows\"."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L047"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(function_name) = context.segment.child(&["function_name"]) else {
+            return Vec::new();
+        };
+
+        if function_name.get_raw_upper().unwrap() != "COUNT" {
+            return Vec::new();
+        }
+
+        let Some(bracketed) = context.segment.child(&["bracketed"]) else {
+            return Vec::new();
+        };
+
+        let f_content: Vec<_> = bracketed
+            .segments()
+            .iter()
+            .filter(|it| it.is_code() && !it.is_type("start_bracket") && !it.is_type("end_bracket"))
+            .cloned()
+            .collect();
+
+        let preferred = self.preferred();
+        let [content] = f_content.as_slice() else {
+            return Vec::new();
+        };
+
+        if content.get_raw().unwrap() == "*" {
+            if preferred == "*" {
+                return Vec::new();
+            }
+
+            return vec![self.result(context.segment.clone(), content.clone(), preferred)];
+        }
+
+        if !content.is_type("expression") {
+            return Vec::new();
+        }
+
+        let expression_content: Vec<_> =
+            content.segments().iter().filter(|it| !it.is_meta()).cloned().collect();
+
+        if let [literal] = expression_content.as_slice()
+            && literal.is_type("numeric_literal")
+            && matches!(literal.get_raw().unwrap().as_str(), "0" | "1")
+            && literal.get_raw().unwrap() != preferred
+        {
+            return vec![self.result(context.segment.clone(), literal.clone(), preferred)];
+        }
+
+        Vec::new()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["function"].into()).into()
+    }
+}
+
+impl RuleCV04 {
+    fn preferred(&self) -> &'static str {
+        if self.prefer_count_1 {
+            "1"
+        } else if self.prefer_count_0 {
+            "0"
+        } else {
+            "*"
+        }
+    }
+
+    fn result(&self, anchor: ErasedSegment, target: ErasedSegment, preferred: &str) -> LintResult {
+        let r#type = if preferred == "*" { "star" } else { "numeric_literal" };
+
+        LintResult::new(
+            anchor.into(),
+            vec![LintFix::replace(
+                target,
+                vec![SymbolSegment::create(
+                    preferred,
+                    &<_>::default(),
+                    SymbolSegmentNewArgs { r#type },
+                )],
+                None,
+            )],
+            None,
+            None,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV04;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(prefer_count_1: bool, prefer_count_0: bool) -> Vec<ErasedRule> {
+        vec![RuleCV04 { prefer_count_1, prefer_count_0 }.erased()]
+    }
+
+    #[test]
+    fn test_pass_count_star() {
+        let violations = lint(
+            "select count(*) from table_a".into(),
+            "ansi".into(),
+            rules(false, false),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_count_1() {
+        let sql = "select count(1) from table_a";
+        let violations = lint(sql.into(), "ansi".into(), rules(false, false), None, None).unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Use consistent syntax to express \"count number of rows\"."
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules(false, false)), "select count(*) from table_a");
+    }
+
+    #[test]
+    fn test_fail_count_0() {
+        assert_eq!(
+            fix("select count(0) from table_a".into(), rules(false, false)),
+            "select count(*) from table_a"
+        );
+    }
+
+    #[test]
+    fn test_pass_count_column() {
+        let violations = lint(
+            "select count(col) from table_a".into(),
+            "ansi".into(),
+            rules(false, false),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_prefer_count_1() {
+        assert_eq!(
+            fix("select count(*) from table_a".into(), rules(true, false)),
+            "select count(1) from table_a"
+        );
+        assert_eq!(
+            fix("select count(0) from table_a".into(), rules(true, true)),
+            "select count(1) from table_a"
+        );
+    }
+
+    #[test]
+    fn test_prefer_count_0() {
+        assert_eq!(
+            fix("select count(1) from table_a".into(), rules(false, true)),
+            "select count(0) from table_a"
+        );
+    }
+}
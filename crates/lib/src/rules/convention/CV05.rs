@@ -0,0 +1,184 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{WhitespaceSegment, WhitespaceSegmentNewArgs};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+
+/// Comparisons with NULL should use "IS" or "IS NOT".
+///
+/// # Anti-pattern
+///
+/// In this example, the `=` operator is used to check for `NULL` values.
+///
+/// ```sql
+/// SELECT
+///     a
+/// FROM foo
+/// WHERE a = NULL
+/// ```
+///
+/// # Best practice
+///
+/// Use `IS` or `IS NOT` to check for `NULL` values.
+///
+/// ```sql
+/// SELECT
+///     a
+/// FROM foo
+/// WHERE a IS NULL
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV05 {}
+
+impl Rule for RuleCV05 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleCV05::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.is_null"
+    }
+
+    fn description(&self) -> &'static str {
+        "Comparisons with NULL should use \"IS\" or \"IS NOT\"."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L049"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(parent) = context.parent_stack.last() else {
+            return Vec::new();
+        };
+
+        // Assignments such as `SET a = NULL` are not comparisons.
+        if parent.is_type("set_clause")
+            || context.parent_stack.len() >= 2
+                && context.parent_stack[context.parent_stack.len() - 2].is_type("set_clause_list")
+        {
+            return Vec::new();
+        }
+
+        // We only care about NULL literals preceded by a comparison operator.
+        let siblings = parent.segments();
+        let Some(operator_idx) =
+            siblings[..context.segment_idx].iter().rposition(|it| it.is_code())
+        else {
+            return Vec::new();
+        };
+
+        let operator = &siblings[operator_idx];
+        let is_not = match operator.get_raw().unwrap().as_str() {
+            "=" => false,
+            _ if operator.is_type("not_equal_to") => true,
+            _ => return Vec::new(),
+        };
+
+        // Match the case of the NULL literal.
+        let uppercase = context.segment.get_raw().unwrap().starts_with('N');
+        let keyword = |raw: &str| {
+            let raw = if uppercase { raw.to_uppercase() } else { raw.to_lowercase() };
+            KeywordSegment::new(raw, None).to_erased_segment()
+        };
+        let whitespace =
+            || WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs);
+
+        let mut edit = Vec::new();
+        if operator_idx > 0 && !siblings[operator_idx - 1].is_whitespace() {
+            edit.push(whitespace());
+        }
+
+        edit.push(keyword("IS"));
+        if is_not {
+            edit.extend([whitespace(), keyword("NOT")]);
+        }
+
+        if !siblings[operator_idx + 1].is_whitespace() {
+            edit.push(whitespace());
+        }
+
+        vec![LintResult::new(
+            operator.clone().into(),
+            vec![LintFix::replace(operator.clone(), edit, None)],
+            None,
+            None,
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["null_literal"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV05;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleCV05::default().erased()]
+    }
+
+    #[test]
+    fn test_is_null() {
+        let violations =
+            lint("SELECT a FROM foo WHERE a IS NULL".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_equals_null_upper() {
+        let sql = "SELECT a FROM foo WHERE a = NULL";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Comparisons with NULL should use \"IS\" or \"IS NOT\".");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules()), "SELECT a FROM foo WHERE a IS NULL");
+    }
+
+    #[test]
+    fn test_equals_null_lower() {
+        assert_eq!(
+            fix("SELECT a FROM foo WHERE a = null".into(), rules()),
+            "SELECT a FROM foo WHERE a is null"
+        );
+    }
+
+    #[test]
+    fn test_not_equals_null() {
+        assert_eq!(
+            fix("SELECT a FROM foo WHERE a != NULL".into(), rules()),
+            "SELECT a FROM foo WHERE a IS NOT NULL"
+        );
+        assert_eq!(
+            fix("SELECT a FROM foo WHERE a <> NULL".into(), rules()),
+            "SELECT a FROM foo WHERE a IS NOT NULL"
+        );
+    }
+
+    #[test]
+    fn test_no_space() {
+        assert_eq!(
+            fix("SELECT a FROM foo WHERE a=NULL".into(), rules()),
+            "SELECT a FROM foo WHERE a IS NULL"
+        );
+    }
+
+    #[test]
+    fn test_update_set_null() {
+        let violations =
+            lint("UPDATE table1 SET col = NULL".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+}
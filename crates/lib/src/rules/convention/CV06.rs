@@ -0,0 +1,280 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    ErasedSegment, NewlineSegment, SymbolSegment, SymbolSegmentNewArgs,
+};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+/// Statements must end with a semi-colon.
+///
+/// # Anti-pattern
+///
+/// A statement is not immediately terminated with a semi-colon. The `•`
+/// represents space.
+///
+/// ```sql
+/// SELECT
+///     a
+/// FROM foo
+///
+/// ;
+///
+/// SELECT
+///     b
+/// FROM bar••;
+/// ```
+///
+/// # Best practice
+///
+/// Immediately terminate the statement with a semi-colon.
+///
+/// ```sql
+/// SELECT
+///     a
+/// FROM foo;
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV06 {
+    multiline_newline: bool,
+    require_final_semicolon: bool,
+}
+
+impl Rule for RuleCV06 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleCV06 {
+            multiline_newline: config.get("multiline_newline").unwrap().as_bool().unwrap(),
+            require_final_semicolon: config
+                .get("require_final_semicolon")
+                .unwrap()
+                .as_bool()
+                .unwrap(),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.terminator"
+    }
+
+    fn description(&self) -> &'static str {
+        "Statements must end with a semi-colon."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L052"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let children = context.segment.segments();
+        let mut results = Vec::new();
+        let mut last_statement_idx = None;
+
+        for (idx, segment) in children.iter().enumerate() {
+            if segment.is_type("statement") {
+                last_statement_idx = Some(idx);
+                continue;
+            }
+
+            if !segment.is_type("statement_terminator") {
+                continue;
+            }
+
+            let Some(statement_idx) = last_statement_idx.take() else {
+                continue;
+            };
+
+            if let Some(result) = self.handle_semicolon(
+                &children[statement_idx],
+                &children[statement_idx + 1..idx],
+                segment,
+            ) {
+                results.push(result);
+            }
+        }
+
+        // A statement without any terminator following it is the final one.
+        if self.require_final_semicolon
+            && let Some(statement_idx) = last_statement_idx
+        {
+            let statement = &children[statement_idx];
+
+            let mut edit = Vec::new();
+            if self.is_multiline(statement) {
+                edit.push(NewlineSegment::create("\n", &<_>::default(), <_>::default()));
+            }
+            edit.push(Self::semicolon());
+
+            results.push(LintResult::new(
+                statement.clone().into(),
+                vec![LintFix::create_after(statement.clone(), edit, None)],
+                None,
+                None,
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+impl RuleCV06 {
+    fn semicolon() -> ErasedSegment {
+        SymbolSegment::create(
+            ";",
+            &<_>::default(),
+            SymbolSegmentNewArgs { r#type: "statement_terminator" },
+        )
+    }
+
+    fn is_multiline(&self, statement: &ErasedSegment) -> bool {
+        self.multiline_newline && statement.get_raw().unwrap().contains('\n')
+    }
+
+    /// Check the segments between a statement and its terminator.
+    fn handle_semicolon(
+        &self,
+        statement: &ErasedSegment,
+        between: &[ErasedSegment],
+        semicolon: &ErasedSegment,
+    ) -> Option<LintResult> {
+        let multiline = self.is_multiline(statement);
+
+        // Multi-line statements are terminated on a new line when configured,
+        // single line ones immediately after the last code.
+        let correctly_placed = if multiline {
+            matches!(between, [newline] if newline.is_type("newline"))
+        } else {
+            between.is_empty()
+        };
+
+        if correctly_placed {
+            return None;
+        }
+
+        let has_comments = between.iter().any(|it| it.is_type("comment"));
+        let mut fixes = Vec::new();
+
+        if has_comments {
+            // Leave comments in place and move the terminator in front of them.
+            let mut edit = Vec::new();
+            if multiline {
+                edit.push(NewlineSegment::create("\n", &<_>::default(), <_>::default()));
+            }
+            edit.push(Self::semicolon());
+
+            fixes.push(LintFix::delete(semicolon.clone()));
+            fixes.push(LintFix::create_after(statement.clone(), edit, None));
+        } else {
+            fixes.extend(between.iter().cloned().map(LintFix::delete));
+
+            if multiline {
+                fixes.push(LintFix::create_before(
+                    semicolon.clone(),
+                    vec![NewlineSegment::create("\n", &<_>::default(), <_>::default())],
+                ));
+            }
+        }
+
+        Some(LintResult::new(semicolon.clone().into(), fixes, None, None, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV06;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(multiline_newline: bool, require_final_semicolon: bool) -> Vec<ErasedRule> {
+        vec![RuleCV06 { multiline_newline, require_final_semicolon }.erased()]
+    }
+
+    #[test]
+    fn test_pass_semi_colon_same_line() {
+        let violations = lint(
+            "SELECT a FROM foo;\nSELECT b FROM bar;\n".into(),
+            "ansi".into(),
+            rules(false, false),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_space_before_semi_colon() {
+        let sql = "SELECT a FROM foo  ;\n";
+        let violations = lint(sql.into(), "ansi".into(), rules(false, false), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Statements must end with a semi-colon.");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules(false, false)), "SELECT a FROM foo;\n");
+    }
+
+    #[test]
+    fn test_fail_newline_before_semi_colon() {
+        assert_eq!(
+            fix("SELECT a\nFROM foo\n\n;\n".into(), rules(false, false)),
+            "SELECT a\nFROM foo;\n"
+        );
+    }
+
+    #[test]
+    fn test_fail_comment_before_semi_colon() {
+        assert_eq!(
+            fix("SELECT a FROM foo -- comment\n;\n".into(), rules(false, false)),
+            "SELECT a FROM foo; -- comment\n\n"
+        );
+    }
+
+    #[test]
+    fn test_pass_no_final_semi_colon() {
+        let violations =
+            lint("SELECT a FROM foo\n".into(), "ansi".into(), rules(false, false), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_require_final_semi_colon() {
+        assert_eq!(
+            fix("SELECT a FROM foo;\nSELECT b FROM bar\n".into(), rules(false, true)),
+            "SELECT a FROM foo;\nSELECT b FROM bar;\n"
+        );
+    }
+
+    #[test]
+    fn test_pass_multiline_newline() {
+        let violations = lint(
+            "SELECT a\nFROM foo\n;\nSELECT b FROM bar;\n".into(),
+            "ansi".into(),
+            rules(true, false),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_multiline_newline() {
+        assert_eq!(
+            fix("SELECT a\nFROM foo;\n".into(), rules(true, false)),
+            "SELECT a\nFROM foo\n;\n"
+        );
+        assert_eq!(
+            fix("SELECT a\nFROM foo\n".into(), rules(true, true)),
+            "SELECT a\nFROM foo\n;\n"
+        );
+    }
+}
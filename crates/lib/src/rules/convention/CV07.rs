@@ -0,0 +1,180 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+/// Top-level statements should not be wrapped in brackets.
+///
+/// # Anti-pattern
+///
+/// A top-level statement is wrapped in brackets.
+///
+/// ```sql
+/// (SELECT
+///     foo
+/// FROM bar)
+///
+/// -- This also applies to statements containing a sub-query.
+///
+/// (SELECT
+///     foo
+/// FROM (SELECT * FROM bar))
+/// ```
+///
+/// # Best practice
+///
+/// Don't wrap top-level statements in brackets.
+///
+/// ```sql
+/// SELECT
+///     foo
+/// FROM bar
+///
+/// -- Likewise for statements containing a sub-query.
+///
+/// SELECT
+///     foo
+/// FROM (SELECT * FROM bar)
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV07 {}
+
+impl Rule for RuleCV07 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleCV07::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.statement_brackets"
+    }
+
+    fn description(&self) -> &'static str {
+        "Top-level statements should not be wrapped in brackets."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L053"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let mut results = Vec::new();
+
+        for statement in context.segment.segments() {
+            if !statement.is_type("statement") {
+                continue;
+            }
+
+            let Some(bracketed) = Self::wrapping_bracket(statement) else {
+                continue;
+            };
+
+            // Unwrap nested brackets down to the inner statement.
+            let mut contents = bracketed.clone();
+            while let Some(inner) = Self::wrapping_bracket(&contents) {
+                contents = inner;
+            }
+
+            results.push(LintResult::new(
+                bracketed.clone().into(),
+                vec![LintFix::replace(bracketed, Self::bracket_contents(&contents), None)],
+                None,
+                None,
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+impl RuleCV07 {
+    /// The bracketed segment if it is the only code within `segment`, ignoring
+    /// the brackets of `segment` itself.
+    fn wrapping_bracket(segment: &ErasedSegment) -> Option<ErasedSegment> {
+        let mut code = segment.segments().iter().filter(|it| {
+            it.is_code() && !it.is_type("start_bracket") && !it.is_type("end_bracket")
+        });
+
+        match (code.next(), code.next()) {
+            (Some(bracketed), None) if bracketed.is_type("bracketed") => Some(bracketed.clone()),
+            _ => None,
+        }
+    }
+
+    /// The contents of a bracketed segment, without the brackets themselves,
+    /// their indents or surrounding whitespace.
+    fn bracket_contents(bracketed: &ErasedSegment) -> Vec<ErasedSegment> {
+        let contents: Vec<_> = bracketed
+            .segments()
+            .iter()
+            .filter(|it| {
+                !it.is_type("start_bracket") && !it.is_type("end_bracket") && !it.is_meta()
+            })
+            .cloned()
+            .collect();
+
+        let start = contents.iter().position(|it| !it.is_whitespace()).unwrap_or(contents.len());
+        let end = contents.iter().rposition(|it| !it.is_whitespace()).map_or(start, |idx| idx + 1);
+
+        contents[start..end].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV07;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleCV07::default().erased()]
+    }
+
+    #[test]
+    fn test_pass_no_brackets() {
+        let violations =
+            lint("SELECT foo FROM (SELECT * FROM bar)".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_outer_brackets() {
+        let sql = "(SELECT foo FROM bar)";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Top-level statements should not be wrapped in brackets.");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules()), "SELECT foo FROM bar");
+    }
+
+    #[test]
+    fn test_fail_outer_brackets_with_subquery() {
+        assert_eq!(
+            fix("(SELECT foo FROM (SELECT * FROM bar))".into(), rules()),
+            "SELECT foo FROM (SELECT * FROM bar)"
+        );
+    }
+
+    #[test]
+    fn test_fail_nested_brackets() {
+        assert_eq!(fix("((SELECT foo FROM bar))\n".into(), rules()), "SELECT foo FROM bar\n");
+    }
+
+    #[test]
+    fn test_fail_multiple_statements() {
+        assert_eq!(
+            fix("(SELECT a FROM foo);\n( SELECT b FROM bar );\n".into(), rules()),
+            "SELECT a FROM foo;\nSELECT b FROM bar;\n"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// Use `LEFT JOIN` instead of `RIGHT JOIN`.
+///
+/// # Anti-pattern
+///
+/// `RIGHT JOIN` is used.
+///
+/// ```sql
+/// SELECT
+///     foo.col1,
+///     bar.col2
+/// FROM foo
+/// RIGHT JOIN bar
+///     ON foo.bar_id = bar.id;
+/// ```
+///
+/// # Best practice
+///
+/// Refactor and use `LEFT JOIN` instead.
+///
+/// ```sql
+/// SELECT
+///     foo.col1,
+///     bar.col2
+/// FROM bar
+/// LEFT JOIN foo
+///    ON foo.bar_id = bar.id;
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV08 {}
+
+impl Rule for RuleCV08 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleCV08::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.left_join"
+    }
+
+    fn description(&self) -> &'static str {
+        "Use 'LEFT JOIN' instead of 'RIGHT JOIN'."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L055"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(first_keyword) =
+            context.segment.segments().iter().find(|it| it.is_type("keyword"))
+        else {
+            return Vec::new();
+        };
+
+        if first_keyword.get_raw_upper().unwrap() == "RIGHT" {
+            return vec![LintResult::new(
+                first_keyword.clone().into(),
+                Vec::new(),
+                None,
+                None,
+                None,
+            )];
+        }
+
+        Vec::new()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["join_clause"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV08;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleCV08::default().erased()]
+    }
+
+    #[test]
+    fn test_fail_right_join() {
+        let violations = lint(
+            "SELECT foo.col1, bar.col2 FROM foo RIGHT JOIN bar ON foo.bar_id = bar.id".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations[0].desc(), "Use 'LEFT JOIN' instead of 'RIGHT JOIN'.");
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_fail_right_outer_join() {
+        let violations = lint(
+            "SELECT foo.col1 FROM foo right outer join bar ON foo.bar_id = bar.id".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_pass_left_join() {
+        let violations = lint(
+            "SELECT foo.col1, bar.col2 FROM bar LEFT JOIN foo ON foo.bar_id = bar.id".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations, []);
+    }
+}
@@ -0,0 +1,219 @@
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+/// Block a list of configurable words from being used.
+///
+/// This generic rule can be useful to prevent certain keywords, functions, or
+/// objects from being used. Only whole words can be blocked, not phrases, nor
+/// parts of words.
+///
+/// This block list is case insensitive.
+///
+/// # Example use cases
+///
+/// * We prefer `BOOL` over `BOOLEAN` and there is no existing rule to enforce
+///   this. Until such a rule is written, we can add `BOOLEAN` to the deny list
+///   to cause a linting error to flag this.
+/// * We have deprecated a schema/table/function and want to prevent it being
+///   used in future. We can add that to the denylist and then add a `-- noqa:
+///   CV09` for the few exceptions that still need to be in the code base for
+///   now.
+///
+/// # Anti-pattern
+///
+/// If the `blocked_words` config is set to `deprecated_table,bool` then the
+/// following will flag:
+///
+/// ```sql
+/// SELECT * FROM deprecated_table WHERE 1 = 1;
+/// CREATE TABLE myschema.t1 (a BOOL);
+/// ```
+///
+/// # Best practice
+///
+/// Do not used any blocked words:
+///
+/// ```sql
+/// SELECT * FROM another_table WHERE 1 = 1;
+/// CREATE TABLE myschema.t1 (a BOOLEAN);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV09 {
+    blocked_words: Vec<String>,
+    blocked_regex: Option<Regex>,
+    match_source: bool,
+}
+
+impl Rule for RuleCV09 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let blocked_words = config
+            .get("blocked_words")
+            .and_then(Value::as_string)
+            .map(|words| {
+                words
+                    .split(',')
+                    .map(|word| word.trim().to_uppercase())
+                    .filter(|word| !word.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let blocked_regex = config
+            .get("blocked_regex")
+            .and_then(Value::as_string)
+            .map(|regex| Regex::new(regex).unwrap());
+
+        RuleCV09 {
+            blocked_words,
+            blocked_regex,
+            match_source: config.get("match_source").unwrap().as_bool().unwrap(),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.blocked_words"
+    }
+
+    fn description(&self) -> &'static str {
+        "Block a list of configurable words from being used."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L062"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        if self.blocked_words.is_empty() && self.blocked_regex.is_none() {
+            return Vec::new();
+        }
+
+        context
+            .segment
+            .get_raw_segments()
+            .into_iter()
+            .filter(|segment| segment.is_code())
+            .filter_map(|segment| self.check_segment(segment))
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+impl RuleCV09 {
+    fn check_segment(&self, segment: ErasedSegment) -> Option<LintResult> {
+        let raw = segment.get_raw().unwrap();
+
+        if self.blocked_words.contains(&raw.to_uppercase()) {
+            let description = format!("Use of blocked word '{raw}'.");
+            return Some(LintResult::new(
+                segment.into(),
+                Vec::new(),
+                None,
+                description.into(),
+                None,
+            ));
+        }
+
+        let regex = self.blocked_regex.as_ref()?;
+
+        let is_match = if self.match_source {
+            let position = segment.get_position_marker().unwrap();
+            let source = &position.templated_file.source_str[position.source_slice.clone()];
+            regex.is_match(source).unwrap()
+        } else {
+            regex.is_match(&raw).unwrap()
+        };
+
+        is_match.then(|| {
+            let description = format!("Use of blocked regex '{raw}'.");
+            LintResult::new(segment.into(), Vec::new(), None, description.into(), None)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fancy_regex::Regex;
+    use pretty_assertions::assert_eq;
+
+    use super::RuleCV09;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(blocked_words: &[&str], blocked_regex: Option<&str>) -> Vec<ErasedRule> {
+        vec![
+            RuleCV09 {
+                blocked_words: blocked_words.iter().map(|word| word.to_uppercase()).collect(),
+                blocked_regex: blocked_regex.map(|regex| Regex::new(regex).unwrap()),
+                match_source: false,
+            }
+            .erased(),
+        ]
+    }
+
+    #[test]
+    fn test_pass_default_none() {
+        let violations = lint(
+            "SELECT col1 FROM deprecated_table\n".into(),
+            "ansi".into(),
+            rules(&[], None),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_deprecated_table() {
+        let violations = lint(
+            "SELECT * FROM DEPRECATED_TABLE WHERE 1 = 1;\nCREATE TABLE myschema.t1 (a BOOL);\n"
+                .into(),
+            "ansi".into(),
+            rules(&["deprecated_table", "bool"], None),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations[0].desc(), "Use of blocked word 'DEPRECATED_TABLE'.");
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_pass_partial_word() {
+        let violations = lint(
+            "SELECT * FROM not_deprecated_table\n".into(),
+            "ansi".into(),
+            rules(&["deprecated_table"], None),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_blocked_regex() {
+        let violations = lint(
+            "SELECT * FROM my_table_deprecated\n".into(),
+            "ansi".into(),
+            rules(&[], Some("_deprecated$")),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations[0].desc(), "Use of blocked regex 'my_table_deprecated'.");
+        assert_eq!(violations.len(), 1);
+    }
+}
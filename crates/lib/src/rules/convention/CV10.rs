@@ -0,0 +1,266 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuoteStyle {
+    Consistent,
+    SingleQuotes,
+    DoubleQuotes,
+}
+
+impl QuoteStyle {
+    fn as_str(self) -> &'static str {
+        match self {
+            QuoteStyle::Consistent => "consistent",
+            QuoteStyle::SingleQuotes => "single_quotes",
+            QuoteStyle::DoubleQuotes => "double_quotes",
+        }
+    }
+
+    fn quote(self) -> char {
+        match self {
+            QuoteStyle::SingleQuotes => '\'',
+            QuoteStyle::DoubleQuotes => '"',
+            QuoteStyle::Consistent => unreachable!(),
+        }
+    }
+}
+
+struct PriorQuoteStyle(QuoteStyle);
+
+/// Consistent usage of preferred quotes for quoted literals.
+///
+/// Some databases allow quoted literals to use either single or double quotes.
+/// Prefer one type of quotes as specified in rule setting, falling back to
+/// alternate quotes to reduce the need for escapes.
+///
+/// Dollar-quoted raw strings are excluded from this rule, as they are mostly
+/// used for literal UDF Body definitions.
+///
+/// Note:
+///    This rule only checks quoted literals and not quoted identifiers as they
+///    often cannot interchange single and double quotes.
+///
+///    This rule is only enabled for dialects that allow single *and* double
+///    quotes for quoted literals. None of the currently supported dialects do,
+///    so it has to be enabled with the `force_enable` configuration parameter.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// select
+///     "abc",
+///     'abc',
+///     "\"",
+///     "abc" = 'abc'
+/// from foo
+/// ```
+///
+/// # Best practice
+///
+/// Ensure all quoted literals use preferred quotes, unless escaping can be
+/// reduced by using alternate quotes.
+///
+/// ```sql
+/// select
+///     "abc",
+///     "abc",
+///     '"',
+///     "abc" = "abc"
+/// from foo
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleCV10 {
+    preferred_quoted_literal_style: QuoteStyle,
+    force_enable: bool,
+}
+
+impl Default for RuleCV10 {
+    fn default() -> Self {
+        Self { preferred_quoted_literal_style: QuoteStyle::Consistent, force_enable: false }
+    }
+}
+
+impl Rule for RuleCV10 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let preferred_quoted_literal_style =
+            match config.get("preferred_quoted_literal_style").unwrap().as_string().unwrap() {
+                "consistent" => QuoteStyle::Consistent,
+                "single_quotes" => QuoteStyle::SingleQuotes,
+                "double_quotes" => QuoteStyle::DoubleQuotes,
+                _ => unreachable!(),
+            };
+
+        RuleCV10 {
+            preferred_quoted_literal_style,
+            force_enable: config.get("force_enable").unwrap().as_bool().unwrap(),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.quoted_literals"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent usage of preferred quotes for quoted literals."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L064"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let raw = context.segment.get_raw().unwrap();
+        let current_style = match raw.chars().next() {
+            Some('\'') => QuoteStyle::SingleQuotes,
+            Some('"') => QuoteStyle::DoubleQuotes,
+            _ => return Vec::new(),
+        };
+
+        let preferred_style = if self.preferred_quoted_literal_style == QuoteStyle::Consistent {
+            let prior_style = context.memory.borrow().get::<PriorQuoteStyle>().map(|prior| prior.0);
+
+            match prior_style {
+                Some(prior_style) => prior_style,
+                None => {
+                    context.memory.borrow_mut().insert(PriorQuoteStyle(current_style));
+                    return Vec::new();
+                }
+            }
+        } else {
+            self.preferred_quoted_literal_style
+        };
+
+        if current_style == preferred_style {
+            return Vec::new();
+        }
+
+        let old_quote = current_style.quote();
+        let new_quote = preferred_style.quote();
+
+        // Unescape the current quotes. If the body contains the preferred
+        // quote it would have to be escaped, so keep the alternate quotes.
+        let body = raw[1..raw.len() - 1]
+            .replace(&format!("{old_quote}{old_quote}"), &old_quote.to_string())
+            .replace(&format!("\\{old_quote}"), &old_quote.to_string());
+        if body.contains(new_quote) {
+            return Vec::new();
+        }
+
+        let fixed_string = format!("{new_quote}{body}{new_quote}");
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            vec![LintFix::replace(
+                context.segment.clone(),
+                vec![SymbolSegment::create(
+                    &fixed_string,
+                    &<_>::default(),
+                    SymbolSegmentNewArgs { r#type: "quoted_literal" },
+                )],
+                None,
+            )],
+            None,
+            format!(
+                "Inconsistent use of preferred quote style '{}'. Use {fixed_string} instead of \
+                 {raw}.",
+                preferred_style.as_str()
+            )
+            .into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["quoted_literal"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{QuoteStyle, RuleCV10};
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(preferred_quoted_literal_style: QuoteStyle, force_enable: bool) -> Vec<ErasedRule> {
+        vec![RuleCV10 { preferred_quoted_literal_style, force_enable }.erased()]
+    }
+
+    #[test]
+    fn test_pass_disabled_by_default() {
+        let violations = lint(
+            "SELECT 'abc' FROM foo".into(),
+            "ansi".into(),
+            rules(QuoteStyle::DoubleQuotes, false),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_consistent() {
+        let violations = lint(
+            "SELECT 'abc', 'def' FROM foo".into(),
+            "ansi".into(),
+            rules(QuoteStyle::Consistent, true),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_preferred_double_quotes() {
+        let sql = "SELECT 'abc' FROM foo";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(QuoteStyle::DoubleQuotes, true), None, None)
+                .unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Inconsistent use of preferred quote style 'double_quotes'. Use \"abc\" instead of \
+             'abc'."
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            fix(sql.into(), rules(QuoteStyle::DoubleQuotes, true)),
+            "SELECT \"abc\" FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_unescapes_quotes() {
+        assert_eq!(
+            fix("SELECT 'It''s' FROM foo".into(), rules(QuoteStyle::DoubleQuotes, true)),
+            "SELECT \"It's\" FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_pass_alternate_quotes_reduce_escapes() {
+        let violations = lint(
+            "SELECT 'say \"hi\"' FROM foo".into(),
+            "ansi".into(),
+            rules(QuoteStyle::DoubleQuotes, true),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+}
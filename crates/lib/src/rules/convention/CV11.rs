@@ -0,0 +1,391 @@
+use ahash::AHashMap;
+use itertools::chain;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    ErasedSegment, Segment, SymbolSegment, SymbolSegmentNewArgs, WhitespaceSegment,
+    WhitespaceSegmentNewArgs,
+};
+use crate::core::parser::segments::bracketed::BracketedSegment;
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::ansi::{FunctionNameSegment, FunctionSegment, Node};
+use crate::helpers::ToErasedSegment;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeCastingStyle {
+    Consistent,
+    Cast,
+    Convert,
+    Shorthand,
+}
+
+struct PriorTypeCastingStyle(TypeCastingStyle);
+
+/// Enforce consistent type casting style.
+///
+/// Note:
+///     This is only applicable to 2-argument `CONVERT` expressions, as
+///     `CONVERT` with a style argument has no `CAST` or `::` equivalent.
+///     Expressions containing comments are reported but not fixed.
+///
+/// # Anti-pattern
+///
+/// Using mixture of `CONVERT`, `::` and `CAST` when
+/// `preferred_type_casting_style` config is set to `consistent` (default).
+///
+/// ```sql
+/// SELECT
+///     CONVERT(int, 1) AS bar,
+///     100::int::text,
+///     CAST(10 AS text) AS coo
+/// FROM foo;
+/// ```
+///
+/// # Best practice
+///
+/// Use consistent type casting style.
+///
+/// ```sql
+/// SELECT
+///     CAST(1 AS int) AS bar,
+///     CAST(CAST(100 AS int) AS text),
+///     CAST(10 AS text) AS coo
+/// FROM foo;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleCV11 {
+    preferred_type_casting_style: TypeCastingStyle,
+}
+
+impl Default for RuleCV11 {
+    fn default() -> Self {
+        Self { preferred_type_casting_style: TypeCastingStyle::Consistent }
+    }
+}
+
+impl Rule for RuleCV11 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let preferred_type_casting_style =
+            match config.get("preferred_type_casting_style").unwrap().as_string().unwrap() {
+                "consistent" => TypeCastingStyle::Consistent,
+                "cast" => TypeCastingStyle::Cast,
+                "convert" => TypeCastingStyle::Convert,
+                "shorthand" => TypeCastingStyle::Shorthand,
+                _ => unreachable!(),
+            };
+
+        RuleCV11 { preferred_type_casting_style }.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.casting_style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Enforce consistent type casting style."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L067"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let current_style = if context.segment.is_type("cast_expression") {
+            TypeCastingStyle::Shorthand
+        } else {
+            let Some(function_name) = context.segment.child(&["function_name"]) else {
+                return Vec::new();
+            };
+
+            match function_name.get_raw_upper().unwrap().as_str() {
+                "CAST" => TypeCastingStyle::Cast,
+                "CONVERT" => TypeCastingStyle::Convert,
+                _ => return Vec::new(),
+            }
+        };
+
+        let (preferred_style, description) =
+            if self.preferred_type_casting_style == TypeCastingStyle::Consistent {
+                let prior_style =
+                    context.memory.borrow().get::<PriorTypeCastingStyle>().map(|prior| prior.0);
+
+                match prior_style {
+                    Some(prior_style) => (prior_style, "Inconsistent type casting styles found."),
+                    None => {
+                        context.memory.borrow_mut().insert(PriorTypeCastingStyle(current_style));
+                        return Vec::new();
+                    }
+                }
+            } else {
+                (
+                    self.preferred_type_casting_style,
+                    "Used type casting style is different from the preferred type casting style.",
+                )
+            };
+
+        if current_style == preferred_style {
+            return Vec::new();
+        }
+
+        let has_comments =
+            context.segment.get_raw_segments().iter().any(|segment| segment.is_type("comment"));
+
+        let fixes = match Self::cast_parts(&context.segment, current_style) {
+            Some((expression, data_types)) if !has_comments => {
+                vec![LintFix::replace(
+                    context.segment.clone(),
+                    Self::build(preferred_style, expression, data_types),
+                    None,
+                )]
+            }
+            _ => Vec::new(),
+        };
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            fixes,
+            None,
+            description.to_owned().into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["function", "cast_expression"].into()).into()
+    }
+}
+
+impl RuleCV11 {
+    /// Split a cast into the expression being cast and the data types it is
+    /// cast to, innermost first.
+    fn cast_parts(
+        segment: &ErasedSegment,
+        style: TypeCastingStyle,
+    ) -> Option<(ErasedSegment, Vec<ErasedSegment>)> {
+        if style == TypeCastingStyle::Shorthand {
+            let code: Vec<_> = segment.segments().iter().filter(|it| it.is_code()).collect();
+            let (expression, rest) = code.split_first()?;
+
+            let mut data_types = Vec::new();
+            for pair in rest.chunks(2) {
+                let [operator, data_type] = pair else { return None };
+                if operator.get_raw().unwrap() != "::" {
+                    return None;
+                }
+                data_types.push((*data_type).clone());
+            }
+
+            return Some(((*expression).clone(), data_types));
+        }
+
+        let bracketed = segment.child(&["bracketed"])?;
+        let arguments: Vec<_> = bracketed
+            .segments()
+            .iter()
+            .filter(|it| it.is_code() && !it.is_type("start_bracket") && !it.is_type("end_bracket"))
+            .cloned()
+            .collect();
+
+        match (style, arguments.as_slice()) {
+            (TypeCastingStyle::Cast, [expression, keyword, data_type])
+                if keyword.get_raw_upper().unwrap() == "AS" =>
+            {
+                Some((expression.clone(), vec![data_type.clone()]))
+            }
+            (TypeCastingStyle::Convert, [data_type, comma, expression])
+                if comma.is_type("comma") =>
+            {
+                Some((expression.clone(), vec![data_type.clone()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Rebuild the cast in the given style, nesting chained casts.
+    fn build(
+        style: TypeCastingStyle,
+        expression: ErasedSegment,
+        data_types: Vec<ErasedSegment>,
+    ) -> Vec<ErasedSegment> {
+        let symbol = |raw: &str, r#type| {
+            SymbolSegment::create(raw, &<_>::default(), SymbolSegmentNewArgs { r#type })
+        };
+        let whitespace =
+            || WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs);
+
+        // Compound expressions need brackets before they can be cast with `::`.
+        let mut simple = expression.segments().iter().filter(|it| it.is_code()).count() <= 1;
+        let mut current = vec![expression];
+
+        for data_type in data_types {
+            current = match style {
+                TypeCastingStyle::Cast => {
+                    let mut arguments = current;
+                    arguments.extend([
+                        whitespace(),
+                        KeywordSegment::new("AS".into(), None).to_erased_segment(),
+                        whitespace(),
+                        data_type,
+                    ]);
+                    vec![Self::function("CAST", arguments)]
+                }
+                TypeCastingStyle::Convert => {
+                    let mut arguments = vec![data_type, symbol(",", "comma"), whitespace()];
+                    arguments.extend(current);
+                    vec![Self::function("CONVERT", arguments)]
+                }
+                TypeCastingStyle::Shorthand => {
+                    let mut edit = Vec::new();
+                    if simple {
+                        edit.extend(current);
+                    } else {
+                        edit.push(symbol("(", "start_bracket"));
+                        edit.extend(current);
+                        edit.push(symbol(")", "end_bracket"));
+                    }
+                    edit.extend([symbol("::", "casting_operator"), data_type]);
+                    edit
+                }
+                TypeCastingStyle::Consistent => unreachable!(),
+            };
+
+            simple = true;
+        }
+
+        current
+    }
+
+    /// A `function` segment calling `name` with the given arguments, so that
+    /// the layout rules space it like a parsed function call.
+    fn function(name: &str, arguments: Vec<ErasedSegment>) -> ErasedSegment {
+        let symbol = |raw: &str, r#type| {
+            SymbolSegment::create(raw, &<_>::default(), SymbolSegmentNewArgs { r#type })
+        };
+
+        let function_name =
+            Node::<FunctionNameSegment>::new().new(vec![symbol(name, "function_name_identifier")]);
+
+        let start_bracket = symbol("(", "start_bracket");
+        let end_bracket = symbol(")", "end_bracket");
+        let mut bracketed = BracketedSegment::new(
+            chain!([start_bracket.clone()], arguments, [end_bracket.clone()]).collect(),
+            vec![start_bracket],
+            vec![end_bracket],
+        );
+        bracketed.pos_marker = None;
+
+        Node::<FunctionSegment>::new().new(vec![function_name, bracketed.to_erased_segment()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{RuleCV11, TypeCastingStyle};
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::layout::LT01::RuleLT01;
+
+    fn rules(preferred_type_casting_style: TypeCastingStyle) -> Vec<ErasedRule> {
+        vec![RuleCV11 { preferred_type_casting_style }.erased()]
+    }
+
+    #[test]
+    fn test_pass_consistent() {
+        let violations = lint(
+            "SELECT CAST(1 AS int), CAST(b AS text) FROM foo".into(),
+            "ansi".into(),
+            rules(TypeCastingStyle::Consistent),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_consistent() {
+        let sql = "SELECT CAST(1 AS int), b::text, CONVERT(int, c) FROM foo";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(TypeCastingStyle::Consistent), None, None)
+                .unwrap();
+
+        assert_eq!(violations[0].desc(), "Inconsistent type casting styles found.");
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            fix(sql.into(), rules(TypeCastingStyle::Consistent)),
+            "SELECT CAST(1 AS int), CAST(b AS text), CAST(c AS int) FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_cast() {
+        assert_eq!(
+            fix("SELECT a::int::text FROM foo".into(), rules(TypeCastingStyle::Cast)),
+            "SELECT CAST(CAST(a AS int) AS text) FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_convert() {
+        assert_eq!(
+            fix("SELECT cast(a AS int), b::text FROM foo".into(), rules(TypeCastingStyle::Convert)),
+            "SELECT CONVERT(int, a), CONVERT(text, b) FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_shorthand() {
+        let sql = "SELECT CAST(a + b AS int), CONVERT(text, c) FROM foo";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(TypeCastingStyle::Shorthand), None, None)
+                .unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Used type casting style is different from the preferred type casting style."
+        );
+        assert_eq!(
+            fix(sql.into(), rules(TypeCastingStyle::Shorthand)),
+            "SELECT (a + b)::int, c::text FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_convert_with_style_not_fixed() {
+        let sql = "SELECT CONVERT(int, a, 1) FROM foo";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(TypeCastingStyle::Cast), None, None).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules(TypeCastingStyle::Cast)), sql);
+    }
+
+    #[test]
+    fn test_fail_cast_with_layout() {
+        let rules = || {
+            let mut rules = rules(TypeCastingStyle::Cast);
+            rules.push(RuleLT01::default().erased());
+            rules
+        };
+
+        assert_eq!(
+            fix("SELECT a::INT, b::INT::TEXT FROM t".into(), rules()),
+            "SELECT CAST(a AS INT), CAST(CAST(b AS INT) AS TEXT) FROM t"
+        );
+        assert_eq!(
+            fix("SELECT CAST(a AS INT) FROM t".into(), {
+                let mut rules = vec![
+                    RuleCV11 { preferred_type_casting_style: TypeCastingStyle::Convert }.erased(),
+                ];
+                rules.push(RuleLT01::default().erased());
+                rules
+            }),
+            "SELECT CONVERT(INT, a) FROM t"
+        );
+    }
+}
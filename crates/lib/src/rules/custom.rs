@@ -0,0 +1,243 @@
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::{split_comma_separated_string, FluffConfig, Value};
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+/// A rule declared in config rather than in code, for conventions which are
+/// specific to a project.
+///
+/// Each custom rule has its own section, named after the code it's selected
+/// by in `rules` and `exclude_rules`:
+///
+/// ```ini
+/// [sqlfluff:custom_rules:X001]
+/// name = custom.legacy_schema
+/// description = The legacy schema mustn't be referenced.
+/// segment_types = table_reference
+/// forbidden_pattern = ^legacy\.
+/// ```
+///
+/// Every segment of one of `segment_types` is checked, and it's a violation
+/// if its raw text matches `forbidden_pattern`, or doesn't match
+/// `required_pattern`. Patterns may use lookaround, e.g. to require tables
+/// in the `raw` schema to be prefixed with `src_`:
+///
+/// ```ini
+/// [sqlfluff:custom_rules:X002]
+/// segment_types = table_reference
+/// required_pattern = ^(?!raw\.)|^raw\.src_
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleCustom {
+    code: Arc<str>,
+    name: Arc<str>,
+    description: Arc<str>,
+    segment_types: Vec<String>,
+    pattern: Regex,
+    /// Whether the pattern has to match, rather than mustn't.
+    required: bool,
+}
+
+impl RuleCustom {
+    /// The rule declared in the section of `code`, or why it can't be.
+    fn from_config(code: &str, config: &AHashMap<String, Value>) -> Result<Self, String> {
+        let get = |key: &str| config.get(key).and_then(Value::as_string);
+
+        let segment_types = get("segment_types").ok_or("`segment_types` is missing")?;
+        let segment_types = split_comma_separated_string(segment_types)
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|segment_type| segment_type.as_string().unwrap().to_owned())
+            .collect();
+
+        let (pattern, required) = match (get("forbidden_pattern"), get("required_pattern")) {
+            (Some(pattern), None) => (pattern, false),
+            (None, Some(pattern)) => (pattern, true),
+            _ => {
+                return Err(
+                    "exactly one of `forbidden_pattern` and `required_pattern` is needed".into()
+                );
+            }
+        };
+        let pattern = Regex::new(pattern).map_err(|err| format!("invalid pattern: {err}"))?;
+
+        let code = code.to_uppercase();
+        let name: Arc<str> = get("name")
+            .map_or_else(|| format!("custom.{}", code.to_lowercase()).into(), Into::into);
+        let description: Arc<str> = get("description").map_or_else(
+            || {
+                let verb = if required { "must match" } else { "mustn't match" };
+                format!("Segment {verb} the pattern `{}`.", pattern.as_str()).into()
+            },
+            Into::into,
+        );
+
+        Ok(Self { code: code.into(), name, description, segment_types, pattern, required })
+    }
+}
+
+impl Rule for RuleCustom {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        self.clone().erased()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn groups(&self) -> &'static [&'static str] {
+        &["all", "custom"]
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let segment_types = self.segment_types.iter().map(String::as_str).collect::<Vec<_>>();
+
+        context
+            .segment
+            .recursive_crawl(&segment_types, true, None, true)
+            .into_iter()
+            .filter(|segment| {
+                let raw = segment.get_raw().unwrap_or_default();
+                self.pattern.is_match(&raw).unwrap_or_default() != self.required
+            })
+            .map(|segment| LintResult::new(segment.into(), Vec::new(), None, None, None))
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+/// The rules declared in the `custom_rules` section of `config`, along with
+/// a warning for each rule which is declared wrongly.
+///
+/// Rules which are declared wrongly are skipped, rather than failing the
+/// whole lint.
+pub fn rules(config: &FluffConfig) -> (Vec<ErasedRule>, Vec<String>) {
+    let Some(sections) = config.raw.get("custom_rules").and_then(Value::as_map) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let mut rules = Vec::with_capacity(sections.len());
+    let mut warnings = Vec::new();
+    for (code, section) in sections {
+        let rule = section
+            .as_map()
+            .ok_or_else(|| "it should be a section".to_string())
+            .and_then(|section| RuleCustom::from_config(code, section));
+
+        match rule {
+            Ok(rule) => rules.push(rule.erased()),
+            Err(err) => {
+                warnings.push(format!("Custom rule {} is skipped, as {err}.", code.to_uppercase()))
+            }
+        }
+    }
+
+    rules.sort_by(|a, b| a.code().cmp(b.code()));
+    (rules, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use tempdir::TempDir;
+
+    use crate::core::config::{ConfigLoader, FluffConfig};
+    use crate::core::linter::linter::Linter;
+
+    const CONFIG: &str = r"[sqlfluff:custom_rules:X001]
+description = The legacy schema mustn't be referenced.
+segment_types = table_reference
+forbidden_pattern = ^legacy\.
+
+[sqlfluff:custom_rules:X002]
+segment_types = table_reference
+required_pattern = ^(?!raw\.)|^raw\.src_
+
+[sqlfluff:custom_rules:X003]
+name = custom.header
+description = Every model must start with a header comment.
+segment_types = file
+required_pattern = ^--
+";
+
+    fn lint(extra_config: &str, sql: &str) -> Vec<(String, String)> {
+        let dir = TempDir::new("sqruff_custom_rules").unwrap();
+        std::fs::write(dir.path().join(".sqlfluff"), format!("{extra_config}\n{CONFIG}")).unwrap();
+        let config = FluffConfig::new(ConfigLoader.load_config_at_path(dir.path()), None, None);
+
+        let mut linter = Linter::new(config, None, None);
+        let rules = linter.get_rulepack().rules();
+        let result = linter.lint_string_wrapped(sql.into(), None, None, rules);
+
+        result.paths[0].files[0]
+            .violations
+            .iter()
+            .map(|violation| (violation.rule_code().to_string(), violation.description.clone()))
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn test__rules__custom() {
+        let violations = lint(
+            "[sqlfluff]\nrules = custom\n",
+            "SELECT a FROM legacy.users JOIN raw.orders USING (id) JOIN raw.src_items USING (id)\n",
+        );
+
+        assert_eq!(
+            violations,
+            [
+                ("X001".into(), "The legacy schema mustn't be referenced.".into()),
+                ("X002".into(), "Segment must match the pattern `^(?!raw\\.)|^raw\\.src_`.".into()),
+                ("X003".into(), "Every model must start with a header comment.".into()),
+            ]
+        );
+
+        assert_eq!(lint("[sqlfluff]\nrules = custom\n", "-- Users.\nSELECT a FROM users\n"), []);
+    }
+
+    #[test]
+    fn test__rules__custom_selection() {
+        let sql = "SELECT a FROM legacy.users\n";
+
+        let codes = |rules: &str| lint(rules, sql).into_iter().map(|(code, _)| code).collect_vec();
+
+        assert_eq!(codes("[sqlfluff]\nrules = X001\n"), ["X001"]);
+        assert_eq!(codes("[sqlfluff]\nrules = custom.header\n"), ["X003"]);
+        assert!(codes("[sqlfluff]\nrules = custom\nexclude_rules = X001,X003\n").is_empty());
+        assert!(codes("[sqlfluff]\n").contains(&"X001".into()));
+    }
+
+    #[test]
+    fn test__rules__custom_invalid() {
+        let dir = TempDir::new("sqruff_custom_rules").unwrap();
+        let config = "[sqlfluff:custom_rules:X004]\nsegment_types = table_reference\n";
+        std::fs::write(dir.path().join(".sqlfluff"), config).unwrap();
+        let config = FluffConfig::new(ConfigLoader.load_config_at_path(dir.path()), None, None);
+
+        let rule_pack = Linter::new(config, None, None).get_rulepack();
+        assert!(rule_pack.rules().iter().all(|rule| rule.code() != "X004"));
+        assert_eq!(
+            rule_pack.warnings,
+            ["Custom rule X004 is skipped, as exactly one of `forbidden_pattern` and \
+              `required_pattern` is needed."]
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! The documentation of the standard rules, which is taken from their doc
+//! comments and the default config when building.
+
+include!(concat!(env!("OUT_DIR"), "/rule_docs.rs"));
+
+/// An option a rule is configured by in its section of the config.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfigOption {
+    pub name: &'static str,
+    pub default: &'static str,
+    /// The comment on the option in the default config, if any.
+    pub description: &'static str,
+}
+
+/// The doc comment of the standard rule with `code`, or an empty string.
+pub fn long_description(code: &str) -> &'static str {
+    RULE_DOCS.iter().find(|(it, _)| *it == code).map_or("", |(_, docs)| docs)
+}
+
+/// The options in the default config section of the rule referred to as
+/// `config_ref` there.
+pub fn config_options(config_ref: &str) -> &'static [ConfigOption] {
+    RULE_CONFIG_OPTIONS.iter().find(|(it, _)| *it == config_ref).map_or(&[], |(_, options)| options)
+}
+
+/// The first SQL example under the `heading` of `docs`, e.g. `Anti-pattern`.
+pub fn sql_example(docs: &'static str, heading: &str) -> Option<&'static str> {
+    let heading = format!("# {}\n", heading.to_lowercase());
+    let start = docs.to_lowercase().find(&heading)? + heading.len();
+
+    let section = &docs[start..];
+    let section = match section.find("\n# ") {
+        Some(end) => &section[..end],
+        None => section,
+    };
+
+    let example = &section[section.find("```sql\n")? + "```sql\n".len()..];
+    Some(&example[..example.find("```")?])
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+
+    use super::*;
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::linter::linter::Linter;
+    use crate::rules::get_ruleset;
+
+    #[test]
+    fn test__rules__docs() {
+        let ruleset = get_ruleset();
+        let cv02 = &ruleset.register["CV02"].rule_class;
+
+        assert!(
+            cv02.long_description().starts_with("Prefer using `COALESCE` over `IFNULL` or `NVL`.")
+        );
+        assert_eq!(
+            cv02.anti_pattern(),
+            Some(
+                "SELECT ifnull(foo, 0) AS bar,\nFROM baz;\n\nSELECT nvl(foo, 0) AS bar,\nFROM \
+                 baz;\n"
+            )
+        );
+        assert_eq!(cv02.best_practice(), Some("SELECT coalesce(foo, 0) AS bar,\nFROM baz;\n"));
+
+        let cp01 = &ruleset.register["CP01"].rule_class;
+        assert_eq!(
+            cp01.config_options()[0],
+            ConfigOption {
+                name: "capitalisation_policy",
+                default: "consistent",
+                description: "Keywords",
+            }
+        );
+    }
+
+    /// Every documented anti-pattern should be flagged by its rule, and the
+    /// best practice shouldn't be.
+    #[test]
+    fn test__rules__docs_examples() {
+        // The examples of these rules are flagged with the config they mention,
+        // or which they're written for.
+        let configs = [
+            ("CV09", "blocked_words", Value::String("deprecated_table,bool".into())),
+            ("CV10", "force_enable", Value::Bool(true)),
+            ("CV10", "preferred_quoted_literal_style", Value::String("double_quotes".into())),
+        ];
+
+        let mut documented = 0;
+        let mut failures = Vec::new();
+
+        for manifest in get_ruleset().register.values() {
+            let rule = &manifest.rule_class;
+            let (Some(anti_pattern), Some(best_practice)) =
+                (rule.anti_pattern(), rule.best_practice())
+            else {
+                continue;
+            };
+            documented += 1;
+
+            let rule_config: AHashMap<_, _> = configs
+                .iter()
+                .filter(|(code, _, _)| *code == rule.code())
+                .map(|(_, key, value)| (key.to_string(), value.clone()))
+                .collect();
+
+            // Docs mark significant spaces with `•`.
+            let codes = |sql: &str| {
+                let sql = sql.replace('•', " ");
+                let config = FluffConfig::new(
+                    [
+                        (
+                            "core".into(),
+                            Value::Map(
+                                [("rules".into(), Value::String(rule.code().into()))].into(),
+                            ),
+                        ),
+                        (
+                            "rules".into(),
+                            Value::Map(
+                                [(rule.config_ref().into(), Value::Map(rule_config.clone()))]
+                                    .into(),
+                            ),
+                        ),
+                    ]
+                    .into(),
+                    None,
+                    None,
+                );
+                let mut linter = Linter::new(config, None, None);
+                let rules = linter.get_rulepack().rules();
+                let result = linter.lint_string_wrapped(sql, None, None, rules);
+
+                result.paths[0].files[0]
+                    .violations
+                    .iter()
+                    .map(|it| it.rule_code().to_owned())
+                    .collect::<Vec<_>>()
+            };
+
+            if !codes(anti_pattern).contains(&rule.code().into()) {
+                failures.push(format!("{} anti-pattern passes", rule.code()));
+            }
+            if codes(best_practice).contains(&rule.code().into()) {
+                failures.push(format!("{} best practice fails", rule.code()));
+            }
+        }
+
+        assert_eq!(failures, Vec::<String>::new());
+        assert!(documented > 0);
+    }
+}
@@ -22,6 +22,10 @@ impl Rule for RuleLT01 {
         "Inappropriate Spacing."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L001", "L005", "L006", "L008", "L023", "L024", "L039", "L048", "L071"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let sequence = ReflowSequence::from_root(context.segment, context.config.unwrap());
         sequence.respace(false, Filter::All).results()
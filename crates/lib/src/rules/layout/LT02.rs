@@ -22,6 +22,10 @@ impl Rule for RuleLT02 {
         "Incorrect Indentation."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L002", "L003", "L004"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         ReflowSequence::from_root(context.segment, context.config.unwrap()).reindent().results()
     }
@@ -23,6 +23,10 @@ impl Rule for RuleLT03 {
         "Operators should follow a standard for being before/after newlines."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L007"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         if context.segment.is_type("") {
             unimplemented!()
@@ -27,6 +27,10 @@ impl Rule for RuleLT04 {
         "Leading/Trailing comma enforcement."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L019"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         if self.check_trail_lead_shortcut(
             &context.segment,
@@ -25,6 +25,10 @@ impl Rule for RuleLT05 {
         "Line is too long."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L016"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let results = ReflowSequence::from_root(context.segment, context.config.unwrap())
             .break_long_lines()
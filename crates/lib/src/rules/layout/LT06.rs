@@ -24,6 +24,10 @@ impl Rule for RuleLT06 {
         "Function name not immediately followed by parenthesis."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L017"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let segment = FunctionalContext::new(context).segment();
         let children = segment.children(None);
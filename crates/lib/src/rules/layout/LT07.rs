@@ -23,6 +23,10 @@ impl Rule for RuleLT07 {
         "'WITH' clause closing bracket should be on a new line."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L018"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let segments = FunctionalContext::new(context.clone())
             .segment()
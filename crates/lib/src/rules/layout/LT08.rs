@@ -26,6 +26,10 @@ impl Rule for RuleLT08 {
         "Blank line expected but not found after CTE closing bracket."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L022"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let mut error_buffer = Vec::new();
         let global_comma_style = "trailing";
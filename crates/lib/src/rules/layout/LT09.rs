@@ -43,6 +43,10 @@ impl Rule for RuleLT09 {
         "Select targets should be on a new line unless there is only one select target."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L036"]
+    }
+
     #[allow(unused_variables)]
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let select_targets_info = Self::get_indexes(context.clone());
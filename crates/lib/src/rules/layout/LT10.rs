@@ -26,6 +26,10 @@ impl Rule for RuleLT10 {
         "'SELECT' modifiers (e.g. 'DISTINCT') must be on the same line as 'SELECT'."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L041"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         // Get children of select_clause and the corresponding select keyword.
         let child_segments = FunctionalContext::new(context.clone()).segment().children(None);
@@ -22,6 +22,10 @@ impl Rule for RuleLT11 {
         "Set operators should be surrounded by newlines."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L065"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         ReflowSequence::from_around_target(
             &context.segment,
@@ -53,6 +53,10 @@ impl Rule for RuleLT12 {
         "Files must end with a single trailing newline."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L009"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let (parent_stack, segment) =
             get_last_segment(FunctionalContext::new(context.clone()).segment());
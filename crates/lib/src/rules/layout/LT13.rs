@@ -23,6 +23,10 @@ impl Rule for RuleLT13 {
         "Files must not begin with newlines or whitespace."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L050"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let mut raw_segments = Vec::new();
 
@@ -178,6 +178,10 @@ impl Rule for RuleRF01 {
         "References cannot reference objects not present in 'FROM' clause."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L026"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let query = Query::from_segment(&context.segment, context.dialect, None);
         let mut violations = Vec::new();
@@ -0,0 +1,207 @@
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::Segment;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+use crate::utils::analysis::select::get_select_statement_info;
+use crate::utils::identifers::{is_ignored_word, parse_ignore_words, parse_ignore_words_regex};
+
+/// References should be qualified if select has more than one referenced
+/// table.
+///
+/// # Anti-pattern
+///
+/// In this example, the reference `vee` has not been declared, and the
+/// variables `a` and `b` are potentially ambiguous.
+///
+/// ```sql
+/// SELECT a, b
+/// FROM foo
+/// LEFT JOIN vee ON vee.a = foo.a
+/// ```
+///
+/// # Best practice
+///
+/// Add the references.
+///
+/// ```sql
+/// SELECT foo.a, vee.b
+/// FROM foo
+/// LEFT JOIN vee ON vee.a = foo.a
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleRF02 {
+    ignore_words: Vec<String>,
+    ignore_words_regex: Option<Regex>,
+}
+
+impl Rule for RuleRF02 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleRF02 {
+            ignore_words: parse_ignore_words(config),
+            ignore_words_regex: parse_ignore_words_regex(config),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "references.qualification"
+    }
+
+    fn description(&self) -> &'static str {
+        "References should be qualified if select has more than one referenced table."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L027"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(select_info) =
+            get_select_statement_info(&context.segment, context.dialect.into(), false)
+        else {
+            return Vec::new();
+        };
+
+        // Only selects from more than one table are ambiguous.
+        if select_info.table_aliases.len() <= 1 {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+
+        for reference in select_info.reference_buffer {
+            if reference.is_qualified() {
+                continue;
+            }
+
+            let raw = reference.get_raw().unwrap();
+
+            // Unqualified wildcards can't be anything else.
+            if raw == "*" {
+                continue;
+            }
+
+            if is_ignored_word(&raw, &self.ignore_words, self.ignore_words_regex.as_ref()) {
+                continue;
+            }
+
+            // Column aliases of the current select and columns joined with
+            // `USING` are not ambiguous.
+            if select_info.col_aliases.iter().any(|alias| alias.alias_identifier_name == raw)
+                || select_info.standalone_aliases.contains(&raw)
+                || select_info.using_cols.contains(&raw)
+            {
+                continue;
+            }
+
+            violations.push(LintResult::new(
+                reference.to_erased_segment().into(),
+                Vec::new(),
+                None,
+                format!(
+                    "Unqualified reference '{raw}' found in select with more than one referenced \
+                     table."
+                )
+                .into(),
+                None,
+            ));
+        }
+
+        violations
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["select_statement"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fancy_regex::Regex;
+    use pretty_assertions::assert_eq;
+
+    use super::RuleRF02;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(ignore_words: &[&str], ignore_words_regex: Option<&str>) -> Vec<ErasedRule> {
+        vec![
+            RuleRF02 {
+                ignore_words: ignore_words.iter().map(|word| word.to_lowercase()).collect(),
+                ignore_words_regex: ignore_words_regex.map(|regex| Regex::new(regex).unwrap()),
+            }
+            .erased(),
+        ]
+    }
+
+    #[test]
+    fn test_pass_single_table() {
+        let violations =
+            lint("SELECT a, b FROM foo".into(), "ansi".into(), rules(&[], None), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_unqualified_multi_table() {
+        let violations = lint(
+            "SELECT a, vee.b FROM foo LEFT JOIN vee ON vee.a = foo.a".into(),
+            "ansi".into(),
+            rules(&[], None),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Unqualified reference 'a' found in select with more than one referenced table."
+        );
+        assert_eq!(violations[0].line_pos, 8);
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_pass_qualified_multi_table() {
+        let violations = lint(
+            "SELECT foo.a, vee.b, foo.* FROM foo LEFT JOIN vee ON vee.a = foo.a".into(),
+            "ansi".into(),
+            rules(&[], None),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_column_alias_and_using() {
+        let violations = lint(
+            "SELECT foo.a AS c, x FROM foo JOIN vee USING (x) ORDER BY c".into(),
+            "ansi".into(),
+            rules(&[], None),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_ignore_words() {
+        let sql = "SELECT a, b FROM foo JOIN vee ON vee.a = foo.a";
+
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(&["A", "b"], None), None, None).unwrap();
+        assert_eq!(violations, []);
+
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(&[], Some("^[ab]$")), None, None).unwrap();
+        assert_eq!(violations, []);
+    }
+}
@@ -0,0 +1,292 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{Segment, SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::ansi::{Node, ObjectReferenceSegment};
+use crate::helpers::ToErasedSegment;
+use crate::utils::analysis::select::get_select_statement_info;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SingleTableReferences {
+    Consistent,
+    Qualified,
+    Unqualified,
+}
+
+impl SingleTableReferences {
+    fn of(reference: &Node<ObjectReferenceSegment>) -> Self {
+        if reference.is_qualified() {
+            SingleTableReferences::Qualified
+        } else {
+            SingleTableReferences::Unqualified
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            SingleTableReferences::Consistent => "Consistent",
+            SingleTableReferences::Qualified => "Qualified",
+            SingleTableReferences::Unqualified => "Unqualified",
+        }
+    }
+}
+
+/// References should be consistent in statements with a single table.
+///
+/// # Anti-pattern
+///
+/// In this example, only the field `b` is referenced.
+///
+/// ```sql
+/// SELECT
+///     a,
+///     foo.b
+/// FROM foo
+/// ```
+///
+/// # Best practice
+///
+/// Add or remove references to all fields.
+///
+/// ```sql
+/// SELECT
+///     a,
+///     b
+/// FROM foo
+///
+/// -- Also good
+///
+/// SELECT
+///     foo.a,
+///     foo.b
+/// FROM foo
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleRF03 {
+    single_table_references: SingleTableReferences,
+}
+
+impl Default for RuleRF03 {
+    fn default() -> Self {
+        Self { single_table_references: SingleTableReferences::Consistent }
+    }
+}
+
+impl Rule for RuleRF03 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let single_table_references =
+            match config.get("single_table_references").unwrap().as_string().unwrap() {
+                "consistent" => SingleTableReferences::Consistent,
+                "qualified" => SingleTableReferences::Qualified,
+                "unqualified" => SingleTableReferences::Unqualified,
+                _ => unreachable!(),
+            };
+
+        RuleRF03 { single_table_references }.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "references.consistent"
+    }
+
+    fn description(&self) -> &'static str {
+        "References should be consistent in statements with a single table."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L028"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(select_info) =
+            get_select_statement_info(&context.segment, context.dialect.into(), false)
+        else {
+            return Vec::new();
+        };
+
+        // Only applies to selects from a single table.
+        let [table_alias] = select_info.table_aliases.as_slice() else {
+            return Vec::new();
+        };
+
+        let mut preferred = self.single_table_references;
+        let mut violations = Vec::new();
+
+        for reference in &select_info.reference_buffer {
+            let raw = reference.get_raw().unwrap();
+
+            // Unqualified wildcards can't be anything else.
+            if raw == "*" {
+                continue;
+            }
+
+            let current = SingleTableReferences::of(reference);
+
+            // Column aliases of the current select can't be qualified.
+            if current == SingleTableReferences::Unqualified
+                && (select_info.col_aliases.iter().any(|alias| alias.alias_identifier_name == raw)
+                    || select_info.standalone_aliases.contains(&raw))
+            {
+                continue;
+            }
+
+            // In consistent mode the first reference decides the style.
+            if preferred == SingleTableReferences::Consistent {
+                preferred = current;
+                continue;
+            }
+
+            if current == preferred {
+                continue;
+            }
+
+            let description = if self.single_table_references == SingleTableReferences::Consistent {
+                format!(
+                    "{} reference '{raw}' found in single table select which is inconsistent with \
+                     previous references.",
+                    current.as_str()
+                )
+            } else {
+                format!("{} reference '{raw}' found in single table select.", current.as_str())
+            };
+
+            let fixes = match preferred {
+                SingleTableReferences::Qualified if !table_alias.ref_str.is_empty() => {
+                    vec![LintFix::create_before(
+                        reference.segments[0].clone(),
+                        vec![
+                            SymbolSegment::create(
+                                &table_alias.ref_str,
+                                &<_>::default(),
+                                SymbolSegmentNewArgs { r#type: "naked_identifier" },
+                            ),
+                            SymbolSegment::create(
+                                ".",
+                                &<_>::default(),
+                                SymbolSegmentNewArgs { r#type: "dot" },
+                            ),
+                        ],
+                    )]
+                }
+                SingleTableReferences::Unqualified => {
+                    let (_, qualifiers) = reference.segments.split_last().unwrap();
+                    qualifiers.iter().cloned().map(LintFix::delete).collect()
+                }
+                _ => Vec::new(),
+            };
+
+            violations.push(LintResult::new(
+                reference.clone().to_erased_segment().into(),
+                fixes,
+                None,
+                description.into(),
+                None,
+            ));
+        }
+
+        violations
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["select_statement"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{RuleRF03, SingleTableReferences};
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(single_table_references: SingleTableReferences) -> Vec<ErasedRule> {
+        vec![RuleRF03 { single_table_references }.erased()]
+    }
+
+    #[test]
+    fn test_pass_consistent() {
+        for sql in ["SELECT a, b FROM foo WHERE c = 1", "SELECT foo.a, foo.b FROM foo"] {
+            let violations = lint(
+                sql.into(),
+                "ansi".into(),
+                rules(SingleTableReferences::Consistent),
+                None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(violations, []);
+        }
+    }
+
+    #[test]
+    fn test_fail_consistent() {
+        let sql = "SELECT a, foo.b FROM foo";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(SingleTableReferences::Consistent), None, None)
+                .unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Qualified reference 'foo.b' found in single table select which is inconsistent with \
+             previous references."
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            fix(sql.into(), rules(SingleTableReferences::Consistent)),
+            "SELECT a, b FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_qualified() {
+        let sql = "SELECT a, f.b FROM foo AS f WHERE c = 1";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(SingleTableReferences::Qualified), None, None)
+                .unwrap();
+
+        assert_eq!(violations[0].desc(), "Unqualified reference 'a' found in single table select.");
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            fix(sql.into(), rules(SingleTableReferences::Qualified)),
+            "SELECT f.a, f.b FROM foo AS f WHERE f.c = 1"
+        );
+    }
+
+    #[test]
+    fn test_fail_unqualified() {
+        assert_eq!(
+            fix("SELECT foo.a, foo.* FROM foo".into(), rules(SingleTableReferences::Unqualified)),
+            "SELECT a, * FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_pass_multi_table() {
+        let violations = lint(
+            "SELECT a, vee.b FROM foo JOIN vee ON vee.a = foo.a".into(),
+            "ansi".into(),
+            rules(SingleTableReferences::Consistent),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_column_alias() {
+        let violations = lint(
+            "SELECT foo.a AS b FROM foo ORDER BY b".into(),
+            "ansi".into(),
+            rules(SingleTableReferences::Qualified),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+}
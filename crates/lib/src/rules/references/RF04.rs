@@ -0,0 +1,212 @@
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::identifers::{
+    identifiers_policy_applicable, is_ignored_word, parse_ignore_words, parse_ignore_words_regex,
+};
+
+/// Keywords should not be used as identifiers.
+///
+/// Although `unreserved` keywords can be used as identifiers, and `reserved
+/// words` can be used as quoted identifiers, best practice is to avoid where
+/// possible, to avoid any misunderstandings as to what the alias represents.
+///
+/// Note:
+///     Note that `reserved` keywords cannot be used as unquoted identifiers
+///     and will cause parsing errors and so are not covered by this rule.
+///
+/// # Anti-pattern
+///
+/// In this example, `SUM` (built-in function) is used as an alias.
+///
+/// ```sql
+/// SELECT
+///     sum.a
+/// FROM foo AS sum
+/// ```
+///
+/// # Best practice
+///
+/// Avoid keywords as the name of an alias.
+///
+/// ```sql
+/// SELECT
+///     vee.a
+/// FROM foo AS vee
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleRF04 {
+    unquoted_identifiers_policy: String,
+    quoted_identifiers_policy: String,
+    ignore_words: Vec<String>,
+    ignore_words_regex: Option<Regex>,
+}
+
+impl Default for RuleRF04 {
+    fn default() -> Self {
+        Self {
+            unquoted_identifiers_policy: "aliases".into(),
+            quoted_identifiers_policy: "none".into(),
+            ignore_words: Vec::new(),
+            ignore_words_regex: None,
+        }
+    }
+}
+
+impl Rule for RuleRF04 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleRF04 {
+            unquoted_identifiers_policy: config
+                .get("unquoted_identifiers_policy")
+                .and_then(Value::as_string)
+                .unwrap_or("none")
+                .to_owned(),
+            quoted_identifiers_policy: config
+                .get("quoted_identifiers_policy")
+                .and_then(Value::as_string)
+                .unwrap_or("none")
+                .to_owned(),
+            ignore_words: parse_ignore_words(config),
+            ignore_words_regex: parse_ignore_words_regex(config),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "references.keywords"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keywords should not be used as identifiers."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L029"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let raw = context.segment.get_raw().unwrap();
+
+        let (identifier, policy) = if context.segment.is_type("quoted_identifier") {
+            (&raw[1..raw.len() - 1], &self.quoted_identifiers_policy)
+        } else {
+            (raw.as_str(), &self.unquoted_identifiers_policy)
+        };
+
+        if !identifiers_policy_applicable(policy, &context.parent_stack) {
+            return Vec::new();
+        }
+
+        if is_ignored_word(identifier, &self.ignore_words, self.ignore_words_regex.as_ref()) {
+            return Vec::new();
+        }
+
+        let identifier = identifier.to_uppercase();
+        if !context.dialect.sets("unreserved_keywords").contains(identifier.as_str())
+            && !context.dialect.sets("reserved_keywords").contains(identifier.as_str())
+        {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(context.segment.clone().into(), Vec::new(), None, None, None)]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["naked_identifier", "quoted_identifier"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleRF04;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(
+        unquoted_identifiers_policy: &str,
+        quoted_identifiers_policy: &str,
+    ) -> Vec<ErasedRule> {
+        vec![
+            RuleRF04 {
+                unquoted_identifiers_policy: unquoted_identifiers_policy.into(),
+                quoted_identifiers_policy: quoted_identifiers_policy.into(),
+                ..Default::default()
+            }
+            .erased(),
+        ]
+    }
+
+    #[test]
+    fn test_pass_no_keywords() {
+        let violations = lint(
+            "SELECT vee.a FROM foo AS vee".into(),
+            "ansi".into(),
+            rules("aliases", "none"),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_keyword_as_alias() {
+        let violations = lint(
+            "SELECT sum.a FROM foo AS sum".into(),
+            "ansi".into(),
+            rules("aliases", "none"),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations[0].desc(), "Keywords should not be used as identifiers.");
+        assert_eq!(violations[0].line_pos, 26);
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_fail_keyword_as_column_all() {
+        let violations = lint(
+            "SELECT sum.a FROM foo AS sum".into(),
+            "ansi".into(),
+            rules("all", "none"),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_fail_quoted_keyword() {
+        let sql = "SELECT a AS \"select\" FROM foo";
+
+        let violations =
+            lint(sql.into(), "ansi".into(), rules("aliases", "none"), None, None).unwrap();
+        assert_eq!(violations, []);
+
+        let violations =
+            lint(sql.into(), "ansi".into(), rules("aliases", "aliases"), None, None).unwrap();
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_pass_ignore_words() {
+        let violations = lint(
+            "SELECT a FROM foo AS sum".into(),
+            "ansi".into(),
+            vec![RuleRF04 { ignore_words: vec!["sum".into()], ..Default::default() }.erased()],
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+}
@@ -0,0 +1,210 @@
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::identifers::{
+    identifiers_policy_applicable, is_ignored_word, parse_ignore_words, parse_ignore_words_regex,
+};
+
+/// Do not use special characters in identifiers.
+///
+/// # Anti-pattern
+///
+/// Using special characters within identifiers when creating or aliasing
+/// objects.
+///
+/// ```sql
+/// CREATE TABLE dbo.column_names
+/// (
+///     "Internal Space" INT,
+///     "Greater>Than" INT,
+///     "Less<Than" INT,
+///     "Number#" INT
+/// )
+/// ```
+///
+/// # Best practice
+///
+/// Identifiers should include only alphanumerics and underscores.
+///
+/// ```sql
+/// CREATE TABLE dbo.column_names
+/// (
+///     "Internal_Space" INT,
+///     "GreaterThan" INT,
+///     "LessThan" INT,
+///     NumberVal INT
+/// )
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleRF05 {
+    unquoted_identifiers_policy: String,
+    quoted_identifiers_policy: String,
+    allow_space_in_identifier: bool,
+    additional_allowed_characters: Vec<char>,
+    ignore_words: Vec<String>,
+    ignore_words_regex: Option<Regex>,
+}
+
+impl Default for RuleRF05 {
+    fn default() -> Self {
+        Self {
+            unquoted_identifiers_policy: "all".into(),
+            quoted_identifiers_policy: "all".into(),
+            allow_space_in_identifier: false,
+            additional_allowed_characters: Vec::new(),
+            ignore_words: Vec::new(),
+            ignore_words_regex: None,
+        }
+    }
+}
+
+impl Rule for RuleRF05 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let additional_allowed_characters = config
+            .get("additional_allowed_characters")
+            .and_then(Value::as_string)
+            .map(|characters| characters.split(',').flat_map(|it| it.trim().chars()).collect())
+            .unwrap_or_default();
+
+        RuleRF05 {
+            unquoted_identifiers_policy: config
+                .get("unquoted_identifiers_policy")
+                .and_then(Value::as_string)
+                .unwrap_or("none")
+                .to_owned(),
+            quoted_identifiers_policy: config
+                .get("quoted_identifiers_policy")
+                .and_then(Value::as_string)
+                .unwrap_or("none")
+                .to_owned(),
+            allow_space_in_identifier: config
+                .get("allow_space_in_identifier")
+                .unwrap()
+                .as_bool()
+                .unwrap(),
+            additional_allowed_characters,
+            ignore_words: parse_ignore_words(config),
+            ignore_words_regex: parse_ignore_words_regex(config),
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "references.special_chars"
+    }
+
+    fn description(&self) -> &'static str {
+        "Do not use special characters in identifiers."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L057"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let raw = context.segment.get_raw().unwrap();
+
+        let (identifier, policy) = if context.segment.is_type("quoted_identifier") {
+            (&raw[1..raw.len() - 1], &self.quoted_identifiers_policy)
+        } else {
+            (raw.as_str(), &self.unquoted_identifiers_policy)
+        };
+
+        if !identifiers_policy_applicable(policy, &context.parent_stack) {
+            return Vec::new();
+        }
+
+        if is_ignored_word(identifier, &self.ignore_words, self.ignore_words_regex.as_ref()) {
+            return Vec::new();
+        }
+
+        let has_special_chars = identifier.chars().any(|ch| {
+            !(ch.is_ascii_alphanumeric()
+                || ch == '_'
+                || ch == ' ' && self.allow_space_in_identifier
+                || self.additional_allowed_characters.contains(&ch))
+        });
+
+        if !has_special_chars {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(context.segment.clone().into(), Vec::new(), None, None, None)]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["naked_identifier", "quoted_identifier"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleRF05;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleRF05::default().erased()]
+    }
+
+    #[test]
+    fn test_pass_no_special_chars() {
+        let violations =
+            lint("SELECT a_1 AS \"b_2\" FROM foo".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_special_chars() {
+        let violations =
+            lint("SELECT \"a-b\", \"c d\" FROM foo".into(), "ansi".into(), rules(), None, None)
+                .unwrap();
+
+        assert_eq!(violations[0].desc(), "Do not use special characters in identifiers.");
+        assert_eq!(violations[0].line_pos, 8);
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_pass_allowed_characters() {
+        let violations = lint(
+            "SELECT \"a-b\", \"c d\" FROM foo".into(),
+            "ansi".into(),
+            vec![
+                RuleRF05 {
+                    allow_space_in_identifier: true,
+                    additional_allowed_characters: vec!['-'],
+                    ..Default::default()
+                }
+                .erased(),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_pass_quoted_policy_aliases() {
+        let violations = lint(
+            "SELECT \"a-b\" AS \"c_d\" FROM foo".into(),
+            "ansi".into(),
+            vec![
+                RuleRF05 { quoted_identifiers_policy: "aliases".into(), ..Default::default() }
+                    .erased(),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+}
@@ -0,0 +1,267 @@
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::identifers::{is_ignored_word, parse_ignore_words, parse_ignore_words_regex};
+
+/// Unnecessary quoted identifier.
+///
+/// This rule will fail if the quotes used to quote an identifier are
+/// (un)necessary depending on the `prefer_quoted_identifiers` configuration.
+/// This rule applies to both column *references* and their *aliases*. The
+/// *default* (safe) behaviour is designed not to unexpectedly corrupt SQL. That
+/// means the circumstances in which quotes can be safely removed depend on how
+/// the current dialect would resolve the unquoted variant of the identifier.
+///
+/// When `prefer_quoted_identifiers = False` (default behaviour), the quotes are
+/// unnecessary, except for reserved keywords and special characters in
+/// identifiers.
+///
+/// # Anti-pattern
+///
+/// In this example, a valid unquoted identifier, that is also not a reserved
+/// keyword, is needlessly quoted.
+///
+/// ```sql
+/// SELECT 123 as "foo"
+/// ```
+///
+/// # Best practice
+///
+/// Use unquoted identifiers where possible.
+///
+/// ```sql
+/// SELECT 123 as foo
+/// ```
+///
+/// When `prefer_quoted_identifiers = True`, the quotes are always necessary, no
+/// matter if the identifier is valid, a reserved keyword, or contains special
+/// characters.
+///
+/// Note:
+///     Different dialects use different quotes, which mean different things in
+///     different contexts, so fixes in this mode only apply ANSI double quotes.
+///
+/// # Anti-pattern
+///
+/// In this example, a valid unquoted identifier, that is also not a reserved
+/// keyword, is required to be quoted.
+///
+/// ```sql
+/// SELECT 123 as foo
+/// ```
+///
+/// # Best practice
+///
+/// Use quoted identifiers.
+///
+/// ```sql
+/// SELECT 123 as "foo"
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleRF06 {
+    prefer_quoted_identifiers: bool,
+    prefer_quoted_keywords: bool,
+    ignore_words: Vec<String>,
+    ignore_words_regex: Option<Regex>,
+    valid_unquoted_identifier: Regex,
+}
+
+impl Default for RuleRF06 {
+    fn default() -> Self {
+        Self {
+            prefer_quoted_identifiers: false,
+            prefer_quoted_keywords: false,
+            ignore_words: Vec::new(),
+            ignore_words_regex: None,
+            valid_unquoted_identifier: Regex::new("^(?i)[A-Z0-9_]*[A-Z][A-Z0-9_]*$").unwrap(),
+        }
+    }
+}
+
+impl Rule for RuleRF06 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleRF06 {
+            prefer_quoted_identifiers: config
+                .get("prefer_quoted_identifiers")
+                .unwrap()
+                .as_bool()
+                .unwrap(),
+            prefer_quoted_keywords: config
+                .get("prefer_quoted_keywords")
+                .unwrap()
+                .as_bool()
+                .unwrap(),
+            ignore_words: parse_ignore_words(config),
+            ignore_words_regex: parse_ignore_words_regex(config),
+            ..Default::default()
+        }
+        .erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "references.quoting"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unnecessary quoted identifier."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L059"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let raw = context.segment.get_raw().unwrap();
+        let is_quoted = context.segment.is_type("quoted_identifier");
+
+        // Only double quotes delimit identifiers.
+        if is_quoted && !raw.starts_with('"') {
+            return Vec::new();
+        }
+
+        let identifier = if is_quoted { &raw[1..raw.len() - 1] } else { raw.as_str() };
+
+        if is_ignored_word(identifier, &self.ignore_words, self.ignore_words_regex.as_ref()) {
+            return Vec::new();
+        }
+
+        let is_keyword = {
+            let upper = identifier.to_uppercase();
+            context.dialect.sets("reserved_keywords").contains(upper.as_str())
+                || context.dialect.sets("unreserved_keywords").contains(upper.as_str())
+        };
+
+        if !is_quoted {
+            let description = if self.prefer_quoted_identifiers {
+                format!("Missing quoted identifier {raw}.")
+            } else if self.prefer_quoted_keywords && is_keyword {
+                format!("Missing quoted keyword {raw}.")
+            } else {
+                return Vec::new();
+            };
+
+            let fix = LintFix::replace(
+                context.segment.clone(),
+                vec![SymbolSegment::create(
+                    &format!("\"{raw}\""),
+                    &<_>::default(),
+                    SymbolSegmentNewArgs { r#type: "quoted_identifier" },
+                )],
+                None,
+            );
+
+            return vec![LintResult::new(
+                context.segment.clone().into(),
+                vec![fix],
+                None,
+                description.into(),
+                None,
+            )];
+        }
+
+        if self.prefer_quoted_identifiers || self.prefer_quoted_keywords && is_keyword {
+            return Vec::new();
+        }
+
+        // The quotes are needed for reserved keywords and anything that isn't a
+        // valid naked identifier.
+        if !self.valid_unquoted_identifier.is_match(identifier).unwrap()
+            || context
+                .dialect
+                .sets("reserved_keywords")
+                .contains(identifier.to_uppercase().as_str())
+        {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            vec![LintFix::replace(
+                context.segment.clone(),
+                vec![SymbolSegment::create(
+                    identifier,
+                    &<_>::default(),
+                    SymbolSegmentNewArgs { r#type: "naked_identifier" },
+                )],
+                None,
+            )],
+            None,
+            format!("Unnecessary quoted identifier {raw}.").into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["naked_identifier", "quoted_identifier"].into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleRF06;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(prefer_quoted_identifiers: bool, prefer_quoted_keywords: bool) -> Vec<ErasedRule> {
+        vec![
+            RuleRF06 { prefer_quoted_identifiers, prefer_quoted_keywords, ..Default::default() }
+                .erased(),
+        ]
+    }
+
+    #[test]
+    fn test_pass_unquoted() {
+        let violations =
+            lint("SELECT 123 AS foo".into(), "ansi".into(), rules(false, false), None, None)
+                .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_unnecessary_quotes() {
+        let sql = "SELECT 123 AS \"foo\"";
+        let violations = lint(sql.into(), "ansi".into(), rules(false, false), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Unnecessary quoted identifier \"foo\".");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules(false, false)), "SELECT 123 AS foo");
+    }
+
+    #[test]
+    fn test_pass_necessary_quotes() {
+        let violations = lint(
+            "SELECT 123 AS \"foo bar\", 456 AS \"select\", 789 AS \"1\"".into(),
+            "ansi".into(),
+            rules(false, false),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_prefer_quoted_identifiers() {
+        let sql = "SELECT 123 AS foo";
+        let violations = lint(sql.into(), "ansi".into(), rules(true, false), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Missing quoted identifier foo.");
+        assert_eq!(fix(sql.into(), rules(true, false)), "SELECT 123 AS \"foo\"");
+    }
+
+    #[test]
+    fn test_fail_prefer_quoted_keywords() {
+        let sql = "SELECT 123 AS \"sum\", 456 AS avg";
+        let violations = lint(sql.into(), "ansi".into(), rules(false, true), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Missing quoted keyword avg.");
+        assert_eq!(violations.len(), 1);
+    }
+}
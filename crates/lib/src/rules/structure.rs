@@ -3,6 +3,12 @@ use crate::core::rules::base::ErasedRule;
 pub mod ST01;
 pub mod ST02;
 pub mod ST03;
+pub mod ST04;
+pub mod ST05;
+pub mod ST06;
+pub mod ST07;
+pub mod ST08;
+pub mod ST09;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -11,5 +17,11 @@ pub fn rules() -> Vec<ErasedRule> {
         ST01::RuleST01::default().erased(),
         ST02::RuleST02::default().erased(),
         ST03::RuleST03::default().erased(),
+        ST04::RuleST04::default().erased(),
+        ST05::RuleST05::default().erased(),
+        ST06::RuleST06::default().erased(),
+        ST07::RuleST07::default().erased(),
+        ST08::RuleST08::default().erased(),
+        ST09::RuleST09::default().erased(),
     ]
 }
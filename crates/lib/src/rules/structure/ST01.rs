@@ -23,6 +23,10 @@ impl Rule for RuleST01 {
         "Do not specify 'else null' in a case when statement (redundant)."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L035"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let anchor = context.segment.clone();
 
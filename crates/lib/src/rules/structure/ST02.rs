@@ -29,6 +29,10 @@ impl Rule for RuleST02 {
         "Unnecessary 'CASE' statement."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L043"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         if context.segment.segments()[0].get_raw().unwrap().eq_ignore_ascii_case("CASE") {
             let children = FunctionalContext::new(context.clone()).segment().children(None);
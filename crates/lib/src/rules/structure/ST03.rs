@@ -25,6 +25,10 @@ impl Rule for RuleST03 {
         "Query defines a CTE (common-table expression) but does not use it."
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L045"]
+    }
+
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         let mut result = Vec::new();
         let query: Query<'_, ()> = Query::from_root(context.segment.clone(), context.dialect);
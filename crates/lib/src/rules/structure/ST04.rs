@@ -0,0 +1,202 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// Nested `CASE` statement in `ELSE` clause could be flattened.
+///
+/// # Anti-pattern
+///
+/// In this example, the outer `CASE`'s `ELSE` is an unnecessary, nested
+/// `CASE`.
+///
+/// ```sql
+/// SELECT
+///   CASE
+///     WHEN species = 'Cat' THEN 'Meow'
+///     ELSE
+///     CASE
+///        WHEN species = 'Dog' THEN 'Woof'
+///     END
+///   END as sound
+/// FROM mytable
+/// ```
+///
+/// # Best practice
+///
+/// Move the body of the inner `CASE` to the end of the outer one.
+///
+/// ```sql
+/// SELECT
+///   CASE
+///     WHEN species = 'Cat' THEN 'Meow'
+///     WHEN species = 'Dog' THEN 'Woof'
+///   END AS sound
+/// FROM mytable
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleST04 {}
+
+impl Rule for RuleST04 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleST04::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.nested_case"
+    }
+
+    fn description(&self) -> &'static str {
+        "Nested CASE statement in ELSE clause could be flattened."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L058"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(else_clause) = context.segment.child(&["else_clause"]) else {
+            return Vec::new();
+        };
+
+        // The ELSE clause must consist of nothing but another CASE.
+        let Some(expression) = else_clause.child(&["expression"]) else {
+            return Vec::new();
+        };
+        let mut code = expression.segments().iter().filter(|it| it.is_code());
+        let inner_case = match (code.next(), code.next()) {
+            (Some(inner_case), None) if inner_case.is_type("case_expression") => inner_case,
+            _ => return Vec::new(),
+        };
+
+        // Only cases switching on the same operand (or none) can be merged.
+        if Self::case_operand(&context.segment) != Self::case_operand(inner_case) {
+            return Vec::new();
+        }
+
+        // Everything from the first WHEN of the inner case up to its END.
+        let inner_segments = inner_case.segments();
+        let Some(start) = inner_segments.iter().position(|it| it.is_type("when_clause")) else {
+            return Vec::new();
+        };
+        let end = inner_segments
+            .iter()
+            .rposition(|it| {
+                it.is_code() && it.get_raw_upper().unwrap() != "END" || it.is_type("comment")
+            })
+            .map_or(start, |idx| idx + 1);
+
+        let edit: Vec<_> =
+            inner_segments[start..end].iter().filter(|it| !it.is_meta()).cloned().collect();
+
+        vec![LintResult::new(
+            inner_case.clone().into(),
+            vec![LintFix::replace(else_clause, edit, None)],
+            None,
+            None,
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["case_expression"].into()).into()
+    }
+}
+
+impl RuleST04 {
+    /// The raw operand of a simple `CASE x WHEN ...` expression, if any.
+    fn case_operand(case: &ErasedSegment) -> Option<String> {
+        let operand: String = case
+            .segments()
+            .iter()
+            .skip(1)
+            .take_while(|it| !it.is_type("when_clause"))
+            .filter(|it| it.is_code())
+            .map(|it| it.get_raw_upper().unwrap())
+            .collect();
+
+        (!operand.is_empty()).then_some(operand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleST04;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleST04::default().erased()]
+    }
+
+    #[test]
+    fn test_pass_flat_case() {
+        let violations = lint(
+            "SELECT CASE WHEN a THEN 1 WHEN b THEN 2 ELSE 3 END FROM t".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_nested_case() {
+        let sql = "SELECT CASE WHEN a THEN 1 ELSE CASE WHEN b THEN 2 ELSE 3 END END FROM t";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Nested CASE statement in ELSE clause could be flattened."
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            fix(sql.into(), rules()),
+            "SELECT CASE WHEN a THEN 1 WHEN b THEN 2 ELSE 3 END FROM t"
+        );
+    }
+
+    #[test]
+    fn test_fail_nested_case_multiline() {
+        assert_eq!(
+            fix(
+                "SELECT\n    CASE\n        WHEN a THEN 1\n        ELSE\n            CASE\n                \
+                 WHEN b THEN 2\n            END\n    END AS c\nFROM t\n"
+                    .into(),
+                rules()
+            ),
+            "SELECT\n    CASE\n        WHEN a THEN 1\n        WHEN b THEN 2\n    END AS c\nFROM t\n"
+        );
+    }
+
+    #[test]
+    fn test_pass_different_operands() {
+        let violations = lint(
+            "SELECT CASE x WHEN 1 THEN 'a' ELSE CASE y WHEN 2 THEN 'b' END END FROM t".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_same_operands() {
+        assert_eq!(
+            fix(
+                "SELECT CASE x WHEN 1 THEN 'a' ELSE CASE x WHEN 2 THEN 'b' END END FROM t".into(),
+                rules()
+            ),
+            "SELECT CASE x WHEN 1 THEN 'a' WHEN 2 THEN 'b' END FROM t"
+        );
+    }
+}
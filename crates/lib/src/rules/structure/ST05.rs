@@ -0,0 +1,379 @@
+use ahash::{AHashMap, AHashSet};
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    ErasedSegment, NewlineSegment, SymbolSegment, SymbolSegmentNewArgs, WhitespaceSegment,
+    WhitespaceSegmentNewArgs,
+};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+use crate::utils::analysis::select::get_aliases_from_select;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ForbidSubqueryIn {
+    Join,
+    From,
+    Both,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Clause {
+    Join,
+    From,
+}
+
+/// Join/From clauses should not contain subqueries. Use CTEs instead.
+///
+/// By default this rule is configured to allow subqueries within `FROM`
+/// clauses but not within `JOIN` clauses. If you prefer a stricter lint
+/// then this is configurable.
+///
+/// Note:
+///     Some dialects don't allow CTEs, and for those dialects
+///     this rule makes no sense and should be disabled.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// select
+///     a.x, a.y, b.z
+/// from a
+/// join (
+///     select x, z from b
+/// ) using(x)
+/// ```
+///
+/// # Best practice
+///
+/// ```sql
+/// with c as (
+///     select x, z from b
+/// )
+/// select
+///     a.x, a.y, c.z
+/// from a
+/// join c using(x)
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleST05 {
+    forbid_subquery_in: ForbidSubqueryIn,
+}
+
+impl Default for RuleST05 {
+    fn default() -> Self {
+        Self { forbid_subquery_in: ForbidSubqueryIn::Join }
+    }
+}
+
+impl Rule for RuleST05 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let forbid_subquery_in =
+            match config.get("forbid_subquery_in").unwrap().as_string().unwrap() {
+                "join" => ForbidSubqueryIn::Join,
+                "from" => ForbidSubqueryIn::From,
+                "both" => ForbidSubqueryIn::Both,
+                _ => unreachable!(),
+            };
+
+        RuleST05 { forbid_subquery_in }.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.subquery"
+    }
+
+    fn description(&self) -> &'static str {
+        "Join/From clauses should not contain subqueries. Use CTEs instead."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L042"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        // Only the outermost query is checked, so that all the CTEs end up in
+        // a single WITH clause.
+        if context.parent_stack.iter().any(|it| {
+            it.is_type("select_statement")
+                || it.is_type("with_compound_statement")
+                || it.is_type("set_expression")
+        }) {
+            return Vec::new();
+        }
+
+        let mut subqueries = Vec::new();
+        self.collect_subqueries(&context.segment, &mut subqueries);
+
+        if subqueries.is_empty() {
+            return Vec::new();
+        }
+
+        let mut cte_names: AHashSet<String> = context
+            .segment
+            .children(&["common_table_expression"])
+            .iter()
+            .filter_map(|cte| cte.child(&["naked_identifier"]))
+            .map(|name| name.get_raw_upper().unwrap())
+            .collect();
+
+        let mut results = Vec::new();
+        let mut new_ctes = Vec::new();
+
+        for (element, bracketed, clause) in subqueries {
+            let alias = element
+                .child(&["alias_expression"])
+                .and_then(|alias| alias.child(&["naked_identifier"]))
+                .map(|alias| alias.get_raw().unwrap());
+
+            let name = alias.clone().unwrap_or_else(|| {
+                (1..)
+                    .map(|idx| format!("prep_{idx}"))
+                    .find(|name| !cte_names.contains(&name.to_uppercase()))
+                    .unwrap()
+            });
+
+            let name_taken = alias.is_some() && cte_names.contains(&name.to_uppercase());
+            let fixable = !(name_taken || Self::is_correlated(&bracketed));
+
+            let mut fixes = Vec::new();
+            if fixable {
+                cte_names.insert(name.to_uppercase());
+                fixes.push(LintFix::replace(
+                    element.clone(),
+                    vec![SymbolSegment::create(
+                        &name,
+                        &<_>::default(),
+                        SymbolSegmentNewArgs { r#type: "naked_identifier" },
+                    )],
+                    None,
+                ));
+                new_ctes.push((name, bracketed.clone()));
+            }
+
+            let clause = match clause {
+                Clause::Join => "Join",
+                Clause::From => "From",
+            };
+
+            results.push(LintResult::new(
+                bracketed.into(),
+                fixes,
+                None,
+                format!("{clause} clauses should not contain subqueries. Use CTEs instead").into(),
+                None,
+            ));
+        }
+
+        // All the new CTEs are added with a single fix, attached to the first
+        // fixable subquery.
+        if let Some(result) = results.iter_mut().find(|result| !result.fixes.is_empty()) {
+            result.fixes.push(Self::insert_ctes(&context.segment, new_ctes));
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            ["select_statement", "set_expression", "with_compound_statement"].into(),
+        )
+        .into()
+    }
+}
+
+impl RuleST05 {
+    /// Find the forbidden subqueries within `segment`, without descending into
+    /// them. Nested subqueries are picked up once their parent has been moved
+    /// into a CTE.
+    fn collect_subqueries(
+        &self,
+        segment: &ErasedSegment,
+        subqueries: &mut Vec<(ErasedSegment, ErasedSegment, Clause)>,
+    ) {
+        for child in segment.segments() {
+            if child.is_type("from_expression_element") {
+                let clause =
+                    if segment.is_type("join_clause") { Clause::Join } else { Clause::From };
+
+                let forbidden = match self.forbid_subquery_in {
+                    ForbidSubqueryIn::Join => clause == Clause::Join,
+                    ForbidSubqueryIn::From => clause == Clause::From,
+                    ForbidSubqueryIn::Both => true,
+                };
+
+                if forbidden && let Some(bracketed) = Self::subquery(child) {
+                    subqueries.push((child.clone(), bracketed, clause));
+                    continue;
+                }
+            }
+
+            self.collect_subqueries(child, subqueries);
+        }
+    }
+
+    /// The bracketed query of a `FROM` or `JOIN` element, if it is one.
+    fn subquery(element: &ErasedSegment) -> Option<ErasedSegment> {
+        let bracketed = element.child(&["table_expression"])?.child(&["bracketed"])?;
+
+        let mut code = bracketed.segments().iter().filter(|it| {
+            it.is_code() && !it.is_type("start_bracket") && !it.is_type("end_bracket")
+        });
+
+        match (code.next(), code.next()) {
+            (Some(query), None)
+                if query.is_type("select_statement") || query.is_type("set_expression") =>
+            {
+                Some(bracketed)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the subquery refers to tables from the outer query, in which
+    /// case it can't be moved.
+    fn is_correlated(subquery: &ErasedSegment) -> bool {
+        let inner_aliases: AHashSet<String> = subquery
+            .recursive_crawl(&["select_statement"], true, None, true)
+            .iter()
+            .flat_map(|select| get_aliases_from_select(select, None).0)
+            .map(|alias| alias.ref_str.to_uppercase())
+            .collect();
+
+        subquery.recursive_crawl(&["column_reference"], true, None, true).iter().any(|reference| {
+            let identifiers = reference.children(&["naked_identifier", "quoted_identifier"]);
+            identifiers.len() > 1
+                && !inner_aliases.contains(&identifiers[0].get_raw_upper().unwrap())
+        })
+    }
+
+    fn insert_ctes(root: &ErasedSegment, ctes: Vec<(String, ErasedSegment)>) -> LintFix {
+        let lowercase = root
+            .get_raw_segments()
+            .iter()
+            .find(|it| it.is_type("keyword"))
+            .map_or(false, |keyword| {
+                keyword.get_raw().unwrap().chars().all(|ch| !ch.is_uppercase())
+            });
+        let keyword = |raw: &str| {
+            let raw = if lowercase { raw.to_lowercase() } else { raw.to_owned() };
+            KeywordSegment::new(raw, None).to_erased_segment()
+        };
+        let whitespace =
+            || WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs);
+        let newline = || NewlineSegment::create("\n", &<_>::default(), <_>::default());
+        let comma = || {
+            SymbolSegment::create(",", &<_>::default(), SymbolSegmentNewArgs { r#type: "comma" })
+        };
+
+        let mut edit = Vec::new();
+        let existing_ctes = root.children(&["common_table_expression"]);
+
+        for (idx, (name, bracketed)) in ctes.into_iter().enumerate() {
+            if idx > 0 || !existing_ctes.is_empty() {
+                edit.extend([comma(), newline()]);
+            }
+
+            edit.extend([
+                SymbolSegment::create(
+                    &name,
+                    &<_>::default(),
+                    SymbolSegmentNewArgs { r#type: "naked_identifier" },
+                ),
+                whitespace(),
+                keyword("AS"),
+                whitespace(),
+                bracketed,
+            ]);
+        }
+
+        match existing_ctes.last() {
+            Some(last_cte) => LintFix::create_after(last_cte.clone(), edit, None),
+            None => {
+                edit.splice(0..0, [keyword("WITH"), whitespace()]);
+                edit.push(newline());
+                LintFix::create_before(root.clone(), edit)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{ForbidSubqueryIn, RuleST05};
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(forbid_subquery_in: ForbidSubqueryIn) -> Vec<ErasedRule> {
+        vec![RuleST05 { forbid_subquery_in }.erased()]
+    }
+
+    #[test]
+    fn test_pass_subquery_in_from() {
+        let violations = lint(
+            "SELECT a.x FROM (SELECT x FROM b) AS a".into(),
+            "ansi".into(),
+            rules(ForbidSubqueryIn::Join),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_subquery_in_join() {
+        let sql = "select a.x, c.z from a join (select x, z from b) as c using (x)\n";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(ForbidSubqueryIn::Join), None, None).unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Join clauses should not contain subqueries. Use CTEs instead"
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            fix(sql.into(), rules(ForbidSubqueryIn::Join)),
+            "with c as (select x, z from b)\nselect a.x, c.z from a join c using (x)\n"
+        );
+    }
+
+    #[test]
+    fn test_fail_subquery_in_from_with_existing_cte() {
+        assert_eq!(
+            fix(
+                "WITH d AS (SELECT 1 AS y)\nSELECT x FROM (SELECT x FROM b)\n".into(),
+                rules(ForbidSubqueryIn::From)
+            ),
+            "WITH d AS (SELECT 1 AS y),\nprep_1 AS (SELECT x FROM b)\nSELECT x FROM prep_1\n"
+        );
+    }
+
+    #[test]
+    fn test_fail_both() {
+        let violations = lint(
+            "SELECT a.x FROM (SELECT x FROM b) AS a JOIN (SELECT x FROM c) AS d ON a.x = d.x"
+                .into(),
+            "ansi".into(),
+            rules(ForbidSubqueryIn::Both),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_fail_correlated_subquery_not_fixed() {
+        let sql = "SELECT a.x FROM a JOIN (SELECT y FROM b WHERE b.x = a.x) AS c ON a.x = c.y\n";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(ForbidSubqueryIn::Join), None, None).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules(ForbidSubqueryIn::Join)), sql);
+    }
+}
@@ -0,0 +1,186 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// Select wildcards then simple targets before calculated targets.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// select
+///     a,
+///     *,
+///     row_number() over (partition by id order by date) as y,
+///     b
+/// from x
+/// ```
+///
+/// # Best practice
+///
+/// Order `select` targets in ascending complexity
+///
+/// ```sql
+/// select
+///     *,
+///     a,
+///     b,
+///     row_number() over (partition by id order by date) as y
+/// from x
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleST06 {}
+
+impl Rule for RuleST06 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleST06::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.column_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Select wildcards then simple targets before calculated targets."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L034"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        // The column order is significant in set expressions and inserts.
+        if context.parent_stack.iter().any(|it| {
+            it.is_type("set_expression")
+                || it.is_type("insert_statement")
+                || it.is_type("create_table_statement")
+        }) {
+            return Vec::new();
+        }
+
+        let elements = context.segment.children(&["select_clause_element"]);
+        let bands: Vec<_> = elements.iter().map(Self::band).collect();
+
+        if bands.windows(2).all(|pair| pair[0] <= pair[1]) {
+            return Vec::new();
+        }
+
+        // Reordering a CTE could change the columns of a `SELECT *` from it,
+        // so those are only reported.
+        let fixes = if context.parent_stack.iter().any(|it| it.is_type("common_table_expression")) {
+            Vec::new()
+        } else {
+            let mut sorted: Vec<_> = elements.iter().zip(&bands).collect();
+            sorted.sort_by_key(|(_, band)| **band);
+
+            elements
+                .iter()
+                .zip(sorted)
+                .filter(|(element, (sorted_element, _))| element != sorted_element)
+                .map(|(element, (sorted_element, _))| {
+                    LintFix::replace(element.clone(), vec![sorted_element.clone()], None)
+                })
+                .collect()
+        };
+
+        vec![LintResult::new(context.segment.clone().into(), fixes, None, None, None)]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["select_clause"].into()).into()
+    }
+}
+
+impl RuleST06 {
+    /// Wildcards come first, then simple targets, then calculated ones.
+    fn band(element: &ErasedSegment) -> u8 {
+        let Some(target) = element.segments().iter().find(|it| it.is_code()) else {
+            return 2;
+        };
+
+        let is_cast_function = target.is_type("function")
+            && target
+                .child(&["function_name"])
+                .map_or(false, |name| name.get_raw_upper().unwrap() == "CAST");
+
+        if target.is_type("wildcard_expression") {
+            0
+        } else if target.is_type("column_reference")
+            || target.is_type("object_reference")
+            || target.is_type("numeric_literal")
+            || target.is_type("quoted_literal")
+            || target.is_type("null_literal")
+            || target.is_type("boolean_literal")
+            || target.is_type("cast_expression")
+            || is_cast_function
+        {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleST06;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleST06::default().erased()]
+    }
+
+    #[test]
+    fn test_pass_ordered() {
+        let violations = lint(
+            "SELECT *, a, 1, CAST(b AS int), c + 1 AS d FROM x".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_unordered() {
+        let sql = "SELECT a, *, c + 1 AS d, b FROM x";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(
+            violations[0].desc(),
+            "Select wildcards then simple targets before calculated targets."
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules()), "SELECT *, a, b, c + 1 AS d FROM x");
+    }
+
+    #[test]
+    fn test_pass_set_expression() {
+        let violations = lint(
+            "SELECT a + 1, b FROM x UNION ALL SELECT c, d FROM y".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_cte_not_fixed() {
+        let sql = "WITH c AS (SELECT a + 1 AS b, d FROM x) SELECT * FROM c";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules()), sql);
+    }
+}
@@ -0,0 +1,258 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    ErasedSegment, SymbolSegment, SymbolSegmentNewArgs, WhitespaceSegment, WhitespaceSegmentNewArgs,
+};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+
+/// Prefer specifying join keys instead of using `USING`.
+///
+/// # Anti-pattern
+///
+/// ```sql
+/// SELECT
+///     table_a.field_1,
+///     table_b.field_2
+/// FROM
+///     table_a
+/// INNER JOIN table_b USING (id)
+/// ```
+///
+/// # Best practice
+///
+/// Specify the keys directly
+///
+/// ```sql
+/// SELECT
+///     table_a.field_1,
+///     table_b.field_2
+/// FROM
+///     table_a
+/// INNER JOIN table_b
+///     ON table_a.id = table_b.id
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleST07 {}
+
+impl Rule for RuleST07 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleST07::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.using"
+    }
+
+    fn description(&self) -> &'static str {
+        "Prefer specifying join keys instead of using `USING`."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L032"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let segments = context.segment.segments();
+
+        let Some(using_idx) = segments
+            .iter()
+            .position(|it| it.is_type("keyword") && it.get_raw_upper().unwrap() == "USING")
+        else {
+            return Vec::new();
+        };
+        let using = &segments[using_idx];
+
+        let Some(bracketed) = segments[using_idx..].iter().find(|it| it.is_type("bracketed"))
+        else {
+            return Vec::new();
+        };
+
+        let columns: Vec<_> = bracketed
+            .segments()
+            .iter()
+            .filter(|it| it.is_type("naked_identifier") || it.is_type("quoted_identifier"))
+            .map(|it| it.get_raw().unwrap())
+            .collect();
+
+        let fixes = match Self::joined_tables(&context.segment, &context.parent_stack) {
+            Some((left, right)) if !columns.is_empty() => vec![
+                LintFix::replace(
+                    using.clone(),
+                    vec![
+                        KeywordSegment::new(Self::matching_case("ON", using), None)
+                            .to_erased_segment(),
+                    ],
+                    None,
+                ),
+                LintFix::replace(
+                    bracketed.clone(),
+                    Self::on_condition(&left, &right, &columns, using),
+                    None,
+                ),
+            ],
+            _ => Vec::new(),
+        };
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            fixes,
+            None,
+            "Found USING statement. Expected only ON statements.".to_owned().into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["join_clause"].into()).into()
+    }
+}
+
+impl RuleST07 {
+    /// The names of the table preceding the join and the joined table.
+    fn joined_tables(
+        join_clause: &ErasedSegment,
+        parent_stack: &[ErasedSegment],
+    ) -> Option<(String, String)> {
+        let from_expression = parent_stack.last()?;
+        let right = Self::table_name(&join_clause.child(&["from_expression_element"])?)?;
+
+        let mut left = None;
+        for segment in from_expression.segments() {
+            if segment == join_clause {
+                break;
+            }
+
+            if segment.is_type("from_expression_element") {
+                left = Self::table_name(segment);
+            } else if segment.is_type("join_clause") {
+                left = segment
+                    .child(&["from_expression_element"])
+                    .and_then(|element| Self::table_name(&element));
+            }
+        }
+
+        Some((left?, right))
+    }
+
+    /// The alias of a `FROM` element, or its table name.
+    fn table_name(element: &ErasedSegment) -> Option<String> {
+        if let Some(alias) = element.child(&["alias_expression"]) {
+            return alias
+                .segments()
+                .iter()
+                .rev()
+                .find(|it| it.is_type("naked_identifier") || it.is_type("quoted_identifier"))
+                .map(|it| it.get_raw().unwrap());
+        }
+
+        let reference = element.child(&["table_expression"])?.child(&["table_reference"])?;
+        reference
+            .get_raw_segments()
+            .iter()
+            .rev()
+            .find(|it| it.is_code())
+            .map(|it| it.get_raw().unwrap())
+    }
+
+    fn on_condition(
+        left: &str,
+        right: &str,
+        columns: &[String],
+        case_of: &ErasedSegment,
+    ) -> Vec<ErasedSegment> {
+        let whitespace =
+            || WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs);
+        let symbol = |raw: &str, r#type| {
+            SymbolSegment::create(raw, &<_>::default(), SymbolSegmentNewArgs { r#type })
+        };
+        let reference = |table: &str, column: &str| {
+            [
+                symbol(table, "naked_identifier"),
+                symbol(".", "dot"),
+                symbol(column, "naked_identifier"),
+            ]
+        };
+
+        let mut edit = Vec::new();
+        for (idx, column) in columns.iter().enumerate() {
+            if idx > 0 {
+                edit.extend([
+                    whitespace(),
+                    KeywordSegment::new(Self::matching_case("AND", case_of), None)
+                        .to_erased_segment(),
+                    whitespace(),
+                ]);
+            }
+
+            edit.extend(reference(left, column));
+            edit.extend([whitespace(), symbol("=", "comparison_operator"), whitespace()]);
+            edit.extend(reference(right, column));
+        }
+
+        edit
+    }
+
+    /// Write `keyword` in the same case as the keyword it replaces.
+    fn matching_case(keyword: &str, case_of: &ErasedSegment) -> String {
+        if case_of.get_raw().unwrap().chars().all(|ch| !ch.is_uppercase()) {
+            keyword.to_lowercase()
+        } else {
+            keyword.to_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleST07;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleST07::default().erased()]
+    }
+
+    #[test]
+    fn test_pass_on() {
+        let violations = lint(
+            "SELECT a.x FROM a JOIN b ON a.id = b.id".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_using() {
+        let sql = "SELECT table_a.x FROM table_a INNER JOIN table_b USING (id)";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "Found USING statement. Expected only ON statements.");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            fix(sql.into(), rules()),
+            "SELECT table_a.x FROM table_a INNER JOIN table_b ON table_a.id = table_b.id"
+        );
+    }
+
+    #[test]
+    fn test_fail_using_multiple_columns_with_aliases() {
+        assert_eq!(
+            fix(
+                "select x from t as a join u as b on a.y = b.y join v as c using (p, q)".into(),
+                rules()
+            ),
+            "select x from t as a join u as b on a.y = b.y join v as c on b.p = c.p and b.q = c.q"
+        );
+    }
+}
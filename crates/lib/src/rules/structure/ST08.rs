@@ -0,0 +1,224 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    ErasedSegment, WhitespaceSegment, WhitespaceSegmentNewArgs,
+};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::helpers::ToErasedSegment;
+
+/// `DISTINCT` used with parentheses.
+///
+/// # Anti-pattern
+///
+/// In this example, parentheses are not needed and confuse `DISTINCT` with a
+/// function. The parentheses can also be misleading about which columns are
+/// affected by the `DISTINCT` (all the columns!).
+///
+/// ```sql
+/// SELECT DISTINCT(a), b FROM foo
+/// ```
+///
+/// # Best practice
+///
+/// Remove parentheses to be clear that the `DISTINCT` applies to both columns.
+///
+/// ```sql
+/// SELECT DISTINCT a, b FROM foo
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RuleST08 {}
+
+impl Rule for RuleST08 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> ErasedRule {
+        RuleST08::default().erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.distinct"
+    }
+
+    fn description(&self) -> &'static str {
+        "`DISTINCT` used with parentheses."
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["L015"]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let fixes = if context.segment.is_type("function") {
+            Self::function_fixes(&context.segment)
+        } else {
+            Self::select_clause_fixes(&context.segment)
+        };
+
+        match fixes {
+            Some((anchor, fixes)) => vec![LintResult::new(anchor.into(), fixes, None, None, None)],
+            None => Vec::new(),
+        }
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["select_clause", "function"].into()).into()
+    }
+}
+
+impl RuleST08 {
+    /// `COUNT(DISTINCT(a))` parses `DISTINCT(a)` as a function call.
+    fn function_fixes(function: &ErasedSegment) -> Option<(ErasedSegment, Vec<LintFix>)> {
+        let name = function.child(&["function_name"])?;
+        if name.get_raw_upper().unwrap() != "DISTINCT" {
+            return None;
+        }
+
+        let bracketed = function.child(&["bracketed"])?;
+        let mut edit = vec![
+            KeywordSegment::new(name.get_raw().unwrap(), None).to_erased_segment(),
+            WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs),
+        ];
+        edit.extend(Self::bracket_contents(&bracketed));
+
+        Some((function.clone(), vec![LintFix::replace(function.clone(), edit, None)]))
+    }
+
+    /// `SELECT DISTINCT(a)` parses `(a)` as the first select target.
+    fn select_clause_fixes(select_clause: &ErasedSegment) -> Option<(ErasedSegment, Vec<LintFix>)> {
+        let segments = select_clause.segments();
+
+        let modifier_idx = segments.iter().position(|it| {
+            it.is_type("select_clause_modifier")
+                && it.segments().iter().any(|it| it.get_raw_upper().unwrap() == "DISTINCT")
+        })?;
+
+        let element_idx =
+            modifier_idx + 1 + segments[modifier_idx + 1..].iter().position(|it| it.is_code())?;
+        let element = &segments[element_idx];
+        if !element.is_type("select_clause_element") {
+            return None;
+        }
+
+        let mut code = element.segments().iter().filter(|it| it.is_code());
+        let expression = match (code.next(), code.next()) {
+            (Some(expression), None) if expression.is_type("expression") => expression,
+            _ => return None,
+        };
+
+        let mut code = expression.segments().iter().filter(|it| it.is_code());
+        let bracketed = match (code.next(), code.next()) {
+            (Some(bracketed), None) if bracketed.is_type("bracketed") => bracketed,
+            _ => return None,
+        };
+
+        // `DISTINCT(a)` needs a space once the brackets are gone, unless there
+        // already is one, which other fixes may have put inside the modifier.
+        let before = segments[modifier_idx..element_idx].iter().filter_map(|it| it.get_raw());
+        let spaced = before.collect::<String>().ends_with(char::is_whitespace)
+            || element.get_raw().unwrap().starts_with(char::is_whitespace);
+
+        let mut edit = Vec::new();
+        if !spaced {
+            edit.push(WhitespaceSegment::create(" ", &<_>::default(), WhitespaceSegmentNewArgs));
+        }
+        edit.extend(Self::bracket_contents(bracketed));
+
+        Some((
+            segments[modifier_idx].clone(),
+            vec![LintFix::replace(bracketed.clone(), edit, None)],
+        ))
+    }
+
+    fn bracket_contents(bracketed: &ErasedSegment) -> Vec<ErasedSegment> {
+        bracketed
+            .segments()
+            .iter()
+            .filter(|it| {
+                !it.is_type("start_bracket") && !it.is_type("end_bracket") && !it.is_meta()
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::RuleST08;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+    use crate::rules::layout::LT01::RuleLT01;
+
+    fn rules() -> Vec<ErasedRule> {
+        vec![RuleST08::default().erased()]
+    }
+
+    #[test]
+    fn test_pass_distinct_without_brackets() {
+        let violations = lint(
+            "SELECT DISTINCT a, b, (c + 1) FROM foo".into(),
+            "ansi".into(),
+            rules(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_distinct_with_brackets() {
+        let sql = "SELECT DISTINCT(a), b FROM foo";
+        let violations = lint(sql.into(), "ansi".into(), rules(), None, None).unwrap();
+
+        assert_eq!(violations[0].desc(), "`DISTINCT` used with parentheses.");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(fix(sql.into(), rules()), "SELECT DISTINCT a, b FROM foo");
+    }
+
+    #[test]
+    fn test_fail_distinct_with_brackets_and_space() {
+        assert_eq!(
+            fix("SELECT DISTINCT (a) FROM foo".into(), rules()),
+            "SELECT DISTINCT a FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_distinct_in_function() {
+        assert_eq!(
+            fix("SELECT COUNT(DISTINCT(b)) FROM foo".into(), rules()),
+            "SELECT COUNT(DISTINCT b) FROM foo"
+        );
+    }
+
+    #[test]
+    fn test_fail_distinct_with_brackets_and_layout() {
+        // Whichever rule runs first, the brackets give way to a single space.
+        for layout_first in [true, false] {
+            let rules = || {
+                let mut rules = vec![RuleST08::default().erased(), RuleLT01::default().erased()];
+                if layout_first {
+                    rules.reverse();
+                }
+                rules
+            };
+
+            assert_eq!(
+                fix("SELECT DISTINCT(a) FROM t".into(), rules()),
+                "SELECT DISTINCT a FROM t"
+            );
+            assert_eq!(
+                fix("SELECT DISTINCT (a) FROM t".into(), rules()),
+                "SELECT DISTINCT a FROM t"
+            );
+            assert_eq!(
+                fix("SELECT COUNT(DISTINCT(b)) FROM t".into(), rules()),
+                "SELECT COUNT(DISTINCT b) FROM t"
+            );
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{ErasedSegment, SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PreferredFirstTable {
+    Earlier,
+    Later,
+}
+
+impl PreferredFirstTable {
+    fn as_str(self) -> &'static str {
+        match self {
+            PreferredFirstTable::Earlier => "earlier",
+            PreferredFirstTable::Later => "later",
+        }
+    }
+}
+
+/// Joins should list the table referenced earlier/later first.
+///
+/// This rule will break down conditions from join clauses into subconditions
+/// using the `AND` and `OR` binary operators.
+///
+/// Subconditions that are made up of a qualified column reference, a
+/// comparison operator and another qualified column reference are then
+/// evaluated to check whether they list the table that was referenced earlier
+/// - or later, depending on the `preferred_first_table_in_join_clause`
+/// configuration.
+///
+/// Subconditions that do not follow that pattern are ignored by this rule.
+///
+/// # Anti-pattern
+///
+/// In this example, the tables that were referenced later are listed first
+/// and the `preferred_first_table_in_join_clause` configuration
+/// is set to `earlier`.
+///
+/// ```sql
+/// select
+///     foo.a,
+///     foo.b,
+///     bar.c
+/// from foo
+/// left join bar
+///     -- This subcondition does not list
+///     -- the table referenced earlier first:
+///     on bar.a = foo.a
+///     -- Neither does this subcondition:
+///     and bar.b = foo.b
+/// ```
+///
+/// # Best practice
+///
+/// List the tables that were referenced earlier first.
+///
+/// ```sql
+/// select
+///     foo.a,
+///     foo.b,
+///     bar.c
+/// from foo
+/// left join bar
+///     on foo.a = bar.a
+///     and foo.b = bar.b
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleST09 {
+    preferred_first_table_in_join_clause: PreferredFirstTable,
+}
+
+impl Default for RuleST09 {
+    fn default() -> Self {
+        Self { preferred_first_table_in_join_clause: PreferredFirstTable::Earlier }
+    }
+}
+
+impl Rule for RuleST09 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> ErasedRule {
+        let preferred_first_table_in_join_clause = match config
+            .get("preferred_first_table_in_join_clause")
+            .unwrap()
+            .as_string()
+            .unwrap()
+        {
+            "earlier" => PreferredFirstTable::Earlier,
+            "later" => PreferredFirstTable::Later,
+            _ => unreachable!(),
+        };
+
+        RuleST09 { preferred_first_table_in_join_clause }.erased()
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.join_condition_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Joins should list the table referenced earlier/later first."
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let mut tables = Vec::new();
+        let mut conditions = Vec::new();
+
+        for segment in context.segment.segments() {
+            if segment.is_type("from_expression_element") {
+                tables.extend(Self::table_name(segment));
+            } else if segment.is_type("join_clause") {
+                tables.extend(
+                    segment
+                        .child(&["from_expression_element"])
+                        .and_then(|element| Self::table_name(&element)),
+                );
+                conditions.extend(segment.child(&["join_on_condition"]));
+            }
+        }
+
+        let mut results = Vec::new();
+
+        for condition in conditions {
+            for expression in
+                condition.recursive_crawl(&["expression"], true, "select_statement".into(), true)
+            {
+                let code: Vec<_> =
+                    expression.segments().iter().filter(|it| it.is_code()).cloned().collect();
+
+                for window in code.windows(3) {
+                    let [left, operator, right] = window else { unreachable!() };
+                    if let Some(result) = self.check_subcondition(&tables, left, operator, right) {
+                        results.push(result);
+                    }
+                }
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(["from_expression"].into()).into()
+    }
+}
+
+impl RuleST09 {
+    fn check_subcondition(
+        &self,
+        tables: &[String],
+        left: &ErasedSegment,
+        operator: &ErasedSegment,
+        right: &ErasedSegment,
+    ) -> Option<LintResult> {
+        if !left.is_type("column_reference") || !right.is_type("column_reference") {
+            return None;
+        }
+
+        let operator_raw: String = operator
+            .get_raw_segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .map(|it| it.get_raw().unwrap())
+            .collect();
+        let mirrored = match operator_raw.as_str() {
+            "=" | "!=" | "<>" => operator_raw.clone(),
+            "<" => ">".into(),
+            ">" => "<".into(),
+            "<=" => ">=".into(),
+            ">=" => "<=".into(),
+            _ => return None,
+        };
+
+        let left_idx = Self::table_index(tables, left)?;
+        let right_idx = Self::table_index(tables, right)?;
+
+        let wrong_order = match self.preferred_first_table_in_join_clause {
+            PreferredFirstTable::Earlier => left_idx > right_idx,
+            PreferredFirstTable::Later => left_idx < right_idx,
+        };
+        if !wrong_order {
+            return None;
+        }
+
+        let mut fixes = vec![
+            LintFix::replace(left.clone(), vec![right.clone()], None),
+            LintFix::replace(right.clone(), vec![left.clone()], None),
+        ];
+        if mirrored != operator_raw {
+            fixes.push(LintFix::replace(
+                operator.clone(),
+                vec![SymbolSegment::create(
+                    &mirrored,
+                    &<_>::default(),
+                    SymbolSegmentNewArgs { r#type: "comparison_operator" },
+                )],
+                None,
+            ));
+        }
+
+        Some(LintResult::new(
+            left.clone().into(),
+            fixes,
+            None,
+            format!(
+                "Joins should list the table referenced {} first.",
+                self.preferred_first_table_in_join_clause.as_str()
+            )
+            .into(),
+            None,
+        ))
+    }
+
+    /// The position in the `FROM` clause of the table a qualified column
+    /// reference refers to.
+    fn table_index(tables: &[String], reference: &ErasedSegment) -> Option<usize> {
+        let identifiers = reference.children(&["naked_identifier", "quoted_identifier"]);
+        let [table, _] = identifiers.as_slice() else {
+            return None;
+        };
+
+        let table = table.get_raw_upper().unwrap();
+        tables.iter().position(|it| *it == table)
+    }
+
+    /// The alias of a `FROM` element, or its table name, in upper case.
+    fn table_name(element: &ErasedSegment) -> Option<String> {
+        if let Some(alias) = element.child(&["alias_expression"]) {
+            return alias
+                .segments()
+                .iter()
+                .rev()
+                .find(|it| it.is_type("naked_identifier") || it.is_type("quoted_identifier"))
+                .map(|it| it.get_raw_upper().unwrap());
+        }
+
+        let reference = element.child(&["table_expression"])?.child(&["table_reference"])?;
+        reference
+            .get_raw_segments()
+            .iter()
+            .rev()
+            .find(|it| it.is_code())
+            .map(|it| it.get_raw_upper().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{PreferredFirstTable, RuleST09};
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::{Erased, ErasedRule};
+
+    fn rules(preferred_first_table_in_join_clause: PreferredFirstTable) -> Vec<ErasedRule> {
+        vec![RuleST09 { preferred_first_table_in_join_clause }.erased()]
+    }
+
+    #[test]
+    fn test_pass_earlier() {
+        let violations = lint(
+            "select foo.a, bar.b from foo left join bar on foo.a = bar.a and foo.b > bar.b".into(),
+            "ansi".into(),
+            rules(PreferredFirstTable::Earlier),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn test_fail_earlier() {
+        let sql = "select foo.a, bar.b from foo left join bar on bar.a = foo.a and bar.b <= foo.b";
+        let violations =
+            lint(sql.into(), "ansi".into(), rules(PreferredFirstTable::Earlier), None, None)
+                .unwrap();
+
+        assert_eq!(violations[0].desc(), "Joins should list the table referenced earlier first.");
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            fix(sql.into(), rules(PreferredFirstTable::Earlier)),
+            "select foo.a, bar.b from foo left join bar on foo.a = bar.a and foo.b >= bar.b"
+        );
+    }
+
+    #[test]
+    fn test_fail_later_with_aliases() {
+        assert_eq!(
+            fix(
+                "SELECT f.a FROM foo AS f JOIN bar AS b ON f.a = b.a".into(),
+                rules(PreferredFirstTable::Later)
+            ),
+            "SELECT f.a FROM foo AS f JOIN bar AS b ON b.a = f.a"
+        );
+    }
+
+    #[test]
+    fn test_pass_unqualified() {
+        let violations = lint(
+            "SELECT foo.a FROM foo JOIN bar ON a = foo.b".into(),
+            "ansi".into(),
+            rules(PreferredFirstTable::Earlier),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(violations, []);
+    }
+}
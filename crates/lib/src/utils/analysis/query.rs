@@ -6,7 +6,7 @@ use ahash::AHashMap;
 use super::select::SelectStatementColumnsAndTables;
 use crate::core::dialects::base::Dialect;
 use crate::core::dialects::common::AliasInfo;
-use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::parser::segments::base::{CloneSegment, ErasedSegment, Segment};
 use crate::utils::analysis::select::get_select_statement_info;
 use crate::utils::functional::segments::Segments;
 
@@ -30,7 +30,18 @@ pub enum QueryType {
     WithCompound,
 }
 
-pub enum WildcardInfo {}
+#[derive(Debug, Clone)]
+pub struct WildcardInfo {
+    pub segment: ErasedSegment,
+    pub tables: Vec<String>,
+}
+
+/// A source of rows for a query: either an external table (or table
+/// function) referenced by name, or another query.
+pub enum Source<'me, T> {
+    External(String),
+    Query(Query<'me, T>),
+}
 
 #[derive(Debug, Clone)]
 pub struct Selectable<'me> {
@@ -76,6 +87,51 @@ impl<'me> Selectable<'me> {
         }
         .into()
     }
+
+    /// Find wildcard (*) targets in the SELECT.
+    pub fn get_wildcard_info(&self) -> Vec<WildcardInfo> {
+        let Some(select_info) = self.select_info() else {
+            return Vec::new();
+        };
+
+        let mut buff = Vec::new();
+        for seg in &select_info.select_targets {
+            if seg.child(&["wildcard_expression"]).is_none() {
+                continue;
+            }
+
+            let raw = seg.get_raw().unwrap();
+            let tables = if let Some((table, _)) = raw.rsplit_once('.') {
+                // The wildcard specifies a target table.
+                vec![table.to_owned()]
+            } else {
+                // The wildcard is unqualified (i.e. does not specify a
+                // table). This means to include all columns from all the
+                // tables in the query.
+                select_info
+                    .table_aliases
+                    .iter()
+                    .filter(|alias_info| !alias_info.ref_str.is_empty())
+                    .map(|alias_info| {
+                        if alias_info.aliased {
+                            alias_info.ref_str.clone()
+                        } else {
+                            alias_info.from_expression_element.get_raw().unwrap()
+                        }
+                    })
+                    .collect()
+            };
+
+            buff.push(WildcardInfo { segment: seg.clone_box(), tables });
+        }
+
+        buff
+    }
+
+    /// Find corresponding table_aliases entry (if any) matching "table".
+    pub fn find_alias(&self, table: &str) -> Option<AliasInfo> {
+        self.select_info()?.table_aliases.into_iter().find(|alias| alias.ref_str == table)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -110,7 +166,7 @@ impl<'me, T: Clone> Query<'me, T> {
     }
 }
 
-impl<T: Default + Clone> Query<'_, T> {
+impl<'me, T: Default + Clone> Query<'me, T> {
     pub fn children(&self) -> Vec<Self> {
         self.inner
             .borrow()
@@ -121,14 +177,79 @@ impl<T: Default + Clone> Query<'_, T> {
             .collect()
     }
 
-    #[allow(dead_code)]
-    fn as_dict() {}
+    /// Look up a CTE by name, in the current or any parent scope.
+    pub fn lookup_cte(&self, name: &str, pop: bool) -> Option<Query<'me, T>> {
+        let name = name.to_uppercase();
 
-    #[allow(dead_code)]
-    fn lookup_cte() {}
+        let cte = if pop {
+            RefCell::borrow_mut(&self.inner).ctes.remove(&name)
+        } else {
+            RefCell::borrow(&self.inner).ctes.get(&name).cloned()
+        };
+
+        if cte.is_some() {
+            return cte;
+        }
+
+        let parent = RefCell::borrow(&self.inner).parent.clone();
+        parent.and_then(|parent| parent.lookup_cte(&name, pop))
+    }
+
+    /// Find SELECTs, table refs, or value table function calls in segment.
+    ///
+    /// For each SELECT, yield a Query. As we find table references or
+    /// function call strings, yield those as external sources.
+    pub fn crawl_sources(
+        &self,
+        segment: &ErasedSegment,
+        pop: bool,
+        lookup_cte: bool,
+    ) -> Vec<Source<'me, T>> {
+        let mut acc = Vec::new();
+        let mut found_nested_select = false;
+
+        for seg in segment.recursive_crawl(
+            &["table_reference", "set_expression", "select_statement", "values_clause"],
+            false,
+            None,
+            false,
+        ) {
+            if seg.is_type("table_reference") {
+                let raw = seg.get_raw().unwrap();
+
+                // If it's a table reference, work out whether it's to a CTE
+                // or to an external table.
+                if !raw.contains('.')
+                    && lookup_cte
+                    && let Some(cte) = self.lookup_cte(&raw, pop)
+                {
+                    acc.push(Source::Query(cte));
+                }
+
+                acc.push(Source::External(raw));
+            } else {
+                // Some kind of more complex expression which is still valid in
+                // this position, generate an appropriate sub-select.
+                found_nested_select = true;
+                acc.push(Source::Query(Query::from_segment(
+                    &seg,
+                    RefCell::borrow(&self.inner).dialect,
+                    self.clone().into(),
+                )));
+            }
+        }
 
-    #[allow(dead_code)]
-    fn crawl_sources() {}
+        if !found_nested_select {
+            // If we reach here, the SELECT may be querying from a value table
+            // function, e.g. UNNEST(). For our purposes, this is basically the
+            // same as an external table. Return the "table" part as a string.
+            if let Some(table_expr) = segment.child(&["table_expression"]) {
+                acc.push(Source::External(table_expr.get_raw().unwrap()));
+            }
+        }
+
+        acc
+    }
 
     fn extract_subqueries<'a>(selectable: &Selectable, dialect: &'a Dialect) -> Vec<Query<'a, T>> {
         let mut acc = Vec::new();
@@ -164,7 +285,10 @@ impl<T: Default + Clone> Query<'_, T> {
         {
             selectables.push(Selectable { selectable: segment.clone(), dialect });
         } else if segment.is_type("set_expression") {
-            unimplemented!()
+            // It's a set expression. There may be multiple selectables.
+            for seg in segment.recursive_crawl(&["select_statement"], false, None, false) {
+                selectables.push(Selectable { selectable: seg, dialect });
+            }
         } else {
             query_type = QueryType::WithCompound;
 
@@ -1,3 +1,7 @@
+use ahash::AHashMap;
+use fancy_regex::Regex;
+
+use crate::core::config::Value;
 use crate::core::parser::segments::base::ErasedSegment;
 
 pub fn identifiers_policy_applicable(policy: &str, parent_stack: &[ErasedSegment]) -> bool {
@@ -20,3 +24,38 @@ pub fn identifiers_policy_applicable(policy: &str, parent_stack: &[ErasedSegment
         }
     }
 }
+
+/// Whether an identifier is excluded by the `ignore_words` and
+/// `ignore_words_regex` options of a rule. Words are compared case
+/// insensitively.
+pub fn is_ignored_word(
+    raw: &str,
+    ignore_words: &[String],
+    ignore_words_regex: Option<&Regex>,
+) -> bool {
+    ignore_words.contains(&raw.to_lowercase())
+        || ignore_words_regex.map_or(false, |regex| regex.is_match(raw).unwrap())
+}
+
+/// Parse the comma separated `ignore_words` option of a rule.
+pub fn parse_ignore_words(config: &AHashMap<String, Value>) -> Vec<String> {
+    config
+        .get("ignore_words")
+        .and_then(Value::as_string)
+        .map(|words| {
+            words
+                .split(',')
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse the `ignore_words_regex` option of a rule.
+pub fn parse_ignore_words_regex(config: &AHashMap<String, Value>) -> Option<Regex> {
+    config
+        .get("ignore_words_regex")
+        .and_then(Value::as_string)
+        .map(|regex| Regex::new(regex).unwrap())
+}
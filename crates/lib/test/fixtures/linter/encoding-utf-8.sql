@@ -0,0 +1 @@
+SELECT 'é' AS a